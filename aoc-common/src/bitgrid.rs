@@ -0,0 +1,158 @@
+use crate::grid::neighbours4;
+
+// Each row is stored in as many u64 words as it takes, bit 0 of the first word
+// being the leftmost cell, so a row can be any width. Plus one spare word, so that
+// a shape row spilling over into the next word never has to check if there is one.
+const WORD_BITS: usize = 64;
+
+/// A grid of occupied (or not) cells, one bit each, for quickly checking if
+/// shapes fit and placing them. Shapes are given as rows of bits, bit 0 being
+/// the leftmost cell, so a shape can be at most 64 cells wide.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    words_per_row: usize,
+    width: usize,
+    height: usize,
+}
+
+impl BitGrid {
+    /// An empty grid
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(WORD_BITS) + 1;
+        BitGrid {
+            words: vec![0; words_per_row * height],
+            words_per_row,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Is the cell at (x, y) occupied? Outside of the grid counts as occupied.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        if x >= self.width || y >= self.height {
+            return true;
+        }
+        self.words[self.word_index(x, y)] & (1 << (x % WORD_BITS)) != 0
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, occupied: bool) {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside of a {}x{} grid", x, y, self.width, self.height);
+        let i = self.word_index(x, y);
+        if occupied {
+            self.words[i] |= 1 << (x % WORD_BITS);
+        } else {
+            self.words[i] &= !(1 << (x % WORD_BITS));
+        }
+    }
+
+    /// The number of occupied cells
+    pub fn count_occupied(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// The empty cells connected to (x, y) through their 4 neighbours, starting with (x, y)
+    /// itself, but only up to `limit` of them. Nothing if (x, y) isn't empty.
+    pub fn empty_area(&self, x: usize, y: usize, limit: usize) -> Vec<(usize, usize)> {
+        let mut area = Vec::new();
+        if self.get(x, y) || limit == 0 {
+            return area;
+        }
+        // The area itself keeps track of what's been visited, it's not meant to be big
+        area.push((x, y));
+        let mut next = 0;
+        while next < area.len() {
+            let (x, y) = area[next];
+            next += 1;
+            for neighbour in neighbours4(x, y, self.width, self.height) {
+                if !self.get(neighbour.0, neighbour.1) && !area.contains(&neighbour) {
+                    area.push(neighbour);
+                    if area.len() == limit {
+                        return area;
+                    }
+                }
+            }
+        }
+        area
+    }
+
+    /// Can the shape fit with its top left corner at (x, y), inside the grid and
+    /// without overlapping anything?
+    #[inline]
+    pub fn can_fit(&self, shape: &[u64], shape_width: usize, x: usize, y: usize) -> bool {
+        // is there room left at all?
+        if x + shape_width > self.width || y + shape.len() > self.height {
+            return false;
+        }
+
+        // This is the hot loop of any packing, so it's kept as plain as possible,
+        // with a special case for the usual shape not spilling over into the next word
+        let (offset, mut i) = (x % WORD_BITS, self.word_index(x, y));
+        if offset + shape_width <= WORD_BITS {
+            for &bits in shape {
+                if self.words[i] & (bits << offset) != 0 {
+                    return false;
+                }
+                i += self.words_per_row;
+            }
+            return true;
+        }
+        for &bits in shape {
+            // overlap not allowed, in either of the words the shape row ends up in
+            let (low, high) = split(bits, offset);
+            if (self.words[i] & low) | (self.words[i + 1] & high) != 0 {
+                return false;
+            }
+            i += self.words_per_row;
+        }
+        true
+    }
+
+    /// Put the shape here, it should fit (see can_fit)
+    #[inline]
+    pub fn place_shape(&mut self, shape: &[u64], x: usize, y: usize) {
+        let (offset, mut i) = (x % WORD_BITS, self.word_index(x, y));
+        for &bits in shape {
+            let (low, high) = split(bits, offset);
+            self.words[i] |= low;
+            self.words[i + 1] |= high;
+            i += self.words_per_row;
+        }
+    }
+
+    /// Remove a shape placed here before
+    #[inline]
+    pub fn remove_shape(&mut self, shape: &[u64], x: usize, y: usize) {
+        let (offset, mut i) = (x % WORD_BITS, self.word_index(x, y));
+        for &bits in shape {
+            let (low, high) = split(bits, offset);
+            self.words[i] &= !low;
+            self.words[i + 1] &= !high;
+            i += self.words_per_row;
+        }
+    }
+
+    #[inline]
+    fn word_index(&self, x: usize, y: usize) -> usize {
+        y * self.words_per_row + x / WORD_BITS
+    }
+}
+
+// Shift a row of a shape to an offset in a word, which might spill over into the
+// next word. Gives the bits in the word and in the next one (shifting in two steps,
+// since shifting a u64 by 64 isn't a thing).
+#[inline]
+fn split(bits: u64, offset: usize) -> (u64, u64) {
+    (bits << offset, (bits >> 1) >> (WORD_BITS - 1 - offset))
+}
+
+#[cfg(test)]
+mod tests;
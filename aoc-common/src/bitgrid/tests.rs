@@ -0,0 +1,81 @@
+use super::*;
+
+// ###
+// #..
+const SHAPE: [u64; 2] = [0b111, 0b001];
+
+#[test]
+fn place_and_remove() {
+    let mut grid = BitGrid::new(5, 3);
+    assert!(grid.can_fit(&SHAPE, 3, 0, 0));
+    grid.place_shape(&SHAPE, 0, 0);
+    assert_eq!(grid.count_occupied(), 4);
+    assert!(grid.get(0, 1) && !grid.get(1, 1));
+
+    // Overlapping, but it fits right next to it, and in the hole under it
+    assert!(!grid.can_fit(&SHAPE, 3, 2, 0));
+    assert!(grid.can_fit(&SHAPE, 3, 1, 1));
+
+    grid.remove_shape(&SHAPE, 0, 0);
+    assert_eq!(grid, BitGrid::new(5, 3));
+}
+
+#[test]
+fn shapes_stay_inside() {
+    let grid = BitGrid::new(5, 3);
+    assert!(grid.can_fit(&SHAPE, 3, 2, 1));
+    assert!(!grid.can_fit(&SHAPE, 3, 3, 0));
+    assert!(!grid.can_fit(&SHAPE, 3, 0, 2));
+    assert!(grid.get(5, 0) && grid.get(0, 3));
+}
+
+#[test]
+fn wider_than_a_word() {
+    let mut grid = BitGrid::new(130, 2);
+
+    // Right across the boundary between the first and second word of the rows
+    grid.place_shape(&SHAPE, 62, 0);
+    assert!(grid.get(62, 0) && grid.get(63, 0) && grid.get(64, 0) && grid.get(62, 1));
+    assert!(!grid.get(61, 0) && !grid.get(65, 0) && !grid.get(63, 1));
+    assert!(!grid.can_fit(&SHAPE, 3, 64, 0));
+    assert!(grid.can_fit(&SHAPE, 3, 65, 0));
+
+    // And all the way at the end
+    assert!(grid.can_fit(&SHAPE, 3, 127, 0));
+    assert!(!grid.can_fit(&SHAPE, 3, 128, 0));
+    grid.place_shape(&SHAPE, 127, 0);
+    assert_eq!(grid.count_occupied(), 8);
+
+    grid.remove_shape(&SHAPE, 62, 0);
+    grid.remove_shape(&SHAPE, 127, 0);
+    assert_eq!(grid.count_occupied(), 0);
+}
+
+#[test]
+fn set_cells() {
+    let mut grid = BitGrid::new(70, 1);
+    grid.set(69, 0, true);
+    assert!(grid.get(69, 0));
+    assert!(!grid.can_fit(&[1], 1, 69, 0));
+    grid.set(69, 0, false);
+    assert!(grid.can_fit(&[1], 1, 69, 0));
+}
+
+#[test]
+fn areas_of_empty_cells() {
+    // ##.#.
+    // .#.#.
+    // ##.##
+    let mut grid = BitGrid::new(5, 3);
+    for (x, y) in [(0, 0), (1, 0), (3, 0), (1, 1), (3, 1), (0, 2), (1, 2), (3, 2), (4, 2)] {
+        grid.set(x, y, true);
+    }
+    assert_eq!(grid.empty_area(2, 0, 10), vec![(2, 0), (2, 1), (2, 2)]);
+    assert_eq!(grid.empty_area(4, 1, 10), vec![(4, 1), (4, 0)]);
+    assert_eq!(grid.empty_area(0, 1, 10), vec![(0, 1)]);
+    assert_eq!(grid.empty_area(0, 0, 10), vec![]);
+
+    // Only up to the limit
+    assert_eq!(grid.empty_area(2, 2, 2).len(), 2);
+    assert_eq!(BitGrid::new(70, 2).empty_area(69, 1, 100).len(), 100);
+}
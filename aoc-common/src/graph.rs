@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Count the paths from `start` to any node where `is_end` holds, following the
+/// edges given by `next`. The graph must not have any cycles (or it'd be infinite
+/// paths anyway), and each node is only counted once thanks to memoization.
+pub fn count_paths<N, I>(start: N, is_end: impl Fn(&N) -> bool, next: impl Fn(&N) -> I) -> u64
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    count_paths_from(start, &is_end, &next, &mut HashMap::new())
+}
+
+fn count_paths_from<N, I>(
+    node: N,
+    is_end: &impl Fn(&N) -> bool,
+    next: &impl Fn(&N) -> I,
+    memo: &mut HashMap<N, u64>,
+) -> u64
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    // we've reached our goal
+    if is_end(&node) {
+        return 1;
+    }
+
+    // Memoization go brrrrr
+    if let Some(&count) = memo.get(&node) {
+        return count;
+    }
+
+    let mut path_count = 0;
+    for next_node in next(&node) {
+        path_count += count_paths_from(next_node, is_end, next, memo);
+    }
+
+    memo.insert(node, path_count);
+    path_count
+}
+
+#[cfg(test)]
+mod tests;
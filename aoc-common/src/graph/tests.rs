@@ -0,0 +1,21 @@
+use super::*;
+
+#[test]
+fn paths_in_a_diamond() {
+    // a -> b -> d, a -> c -> d, and a dead end at e
+    let edges = |node: &char| match node {
+        'a' => vec!['b', 'c', 'e'],
+        'b' | 'c' => vec!['d'],
+        _ => vec![],
+    };
+    assert_eq!(count_paths('a', |&node| node == 'd', edges), 2);
+    assert_eq!(count_paths('a', |&node| node == 'e', edges), 1);
+    assert_eq!(count_paths('d', |&node| node == 'a', edges), 0);
+}
+
+#[test]
+fn paths_through_a_ladder() {
+    // Every step down can go to either of two nodes, so the paths double with each step
+    let next = |&(step, _): &(u32, bool)| [(step + 1, false), (step + 1, true)];
+    assert_eq!(count_paths((0, false), |&(step, _)| step == 40, next), 1 << 40);
+}
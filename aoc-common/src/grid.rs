@@ -0,0 +1,191 @@
+use crate::{ParseError, lines};
+use std::ops::{Index, IndexMut};
+
+/// A 2D grid of cells, stored flat row by row. Positions are (x, y), with
+/// (0, 0) at the top left, like the puzzle inputs read.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid from its cells, row by row
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "a {}x{} grid needs {} cells", width, height, width * height);
+        Grid { cells, width, height }
+    }
+
+    /// A grid with every cell the same
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parse a grid with a character for each cell, all the lines being just as wide.
+    /// The cell function says what each character is, or None if it's not valid, and
+    /// then the error says what was expected instead.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        cell: impl Fn(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for line in lines(day, input) {
+            let row_start = cells.len();
+            for (i, c) in line.text.char_indices() {
+                let value = cell(c)
+                    .ok_or_else(|| line.error(&line.text[i..i + c.len_utf8()], format!("expected {}", expected)))?;
+                cells.push(value);
+            }
+
+            let row_width = cells.len() - row_start;
+            if height == 0 {
+                width = row_width;
+            } else if row_width != width {
+                return Err(line.error(line.text, format!("expected a row of {} cells, like the first one", width)));
+            }
+            height += 1;
+        }
+
+        if width == 0 {
+            return Err(ParseError::new(day, 1, 1, "", "the grid is empty"));
+        }
+
+        Ok(Grid { cells, width, height })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at (x, y), or None if that's outside of the grid
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// All the positions in the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |i| (i % width, i / width))
+    }
+
+    /// The positions of the up to 4 neighbours of (x, y), straight up, right, down and left
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        neighbours4(x, y, self.width, self.height)
+    }
+
+    /// The positions of the up to 8 neighbours of (x, y), including the diagonal ones
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        neighbours8(x, y, self.width, self.height)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// The cells of column x, from the top and down
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside of a grid {} wide", x, self.width);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Columns become rows and rows become columns
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for x in 0..self.width {
+            cells.extend(self.column(x).cloned());
+        }
+        Grid::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of a {}x{} grid", x, y, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of a {}x{} grid", x, y, width, height))
+    }
+}
+
+// Offsets to the neighbours, clockwise from straight up
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const ALL_AROUND: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// The up to 4 neighbours of (x, y) straight up, right, down and left,
+/// that are inside a grid of the given size
+pub fn neighbours4(x: usize, y: usize, width: usize, height: usize) -> impl Iterator<Item = (usize, usize)> {
+    neighbours(&ORTHOGONAL, x, y, width, height)
+}
+
+/// The up to 8 neighbours of (x, y), including the diagonal ones,
+/// that are inside a grid of the given size
+pub fn neighbours8(x: usize, y: usize, width: usize, height: usize) -> impl Iterator<Item = (usize, usize)> {
+    neighbours(&ALL_AROUND, x, y, width, height)
+}
+
+// No more saturating_sub and min(height - 1) to stay inside the grid...
+fn neighbours(
+    offsets: &'static [(isize, isize)],
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    offsets.iter().filter_map(move |&(dx, dy)| {
+        let nx = x.checked_add_signed(dx).filter(|&nx| nx < width)?;
+        let ny = y.checked_add_signed(dy).filter(|&ny| ny < height)?;
+        Some((nx, ny))
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,82 @@
+use super::*;
+
+#[test]
+fn neighbours_in_the_middle() {
+    assert_eq!(neighbours4(1, 1, 3, 3).count(), 4);
+    assert_eq!(neighbours8(1, 1, 3, 3).count(), 8);
+    assert!(!neighbours8(1, 1, 3, 3).any(|n| n == (1, 1)));
+}
+
+#[test]
+fn neighbours_in_the_corners() {
+    let mut top_left: Vec<(usize, usize)> = neighbours8(0, 0, 3, 2).collect();
+    top_left.sort();
+    assert_eq!(top_left, vec![(0, 1), (1, 0), (1, 1)]);
+
+    let bottom_right: Vec<(usize, usize)> = neighbours4(2, 1, 3, 2).collect();
+    assert_eq!(bottom_right, vec![(2, 0), (1, 1)]);
+}
+
+#[test]
+fn neighbours_in_a_single_cell() {
+    assert_eq!(neighbours8(0, 0, 1, 1).count(), 0);
+}
+
+fn example() -> Grid<u8> {
+    Grid::parse(4, "..@\n@@.\n", |c| matches!(c, '.' | '@').then_some(c as u8), "'.' or '@'").unwrap()
+}
+
+#[test]
+fn parse_and_get() {
+    let grid = example();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(2, 0), Some(&b'@'));
+    assert_eq!(grid[(1, 1)], b'@');
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.get(0, 2), None);
+}
+
+#[test]
+fn parse_errors() {
+    let cell = |c| matches!(c, '.' | '@').then_some(c);
+    let error = Grid::parse(4, "..@\n@x.\n", cell, "'.' or '@'").unwrap_err();
+    assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "x"));
+    assert_eq!(error.message, "expected '.' or '@'");
+
+    let error = Grid::parse(4, "..@\n@.\n", cell, "'.' or '@'").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+
+    assert!(Grid::parse(4, "", cell, "'.' or '@'").is_err());
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = example();
+    assert_eq!(grid.row(1), b"@@.");
+    assert_eq!(grid.rows().collect::<Vec<_>>(), vec![b"..@", b"@@."]);
+    assert_eq!(grid.column(0).copied().collect::<Vec<_>>(), b".@");
+    assert_eq!(grid.column(2).rev().copied().collect::<Vec<_>>(), b".@");
+}
+
+#[test]
+fn transpose() {
+    let grid = example();
+    let transposed = grid.transpose();
+    assert_eq!((transposed.width(), transposed.height()), (2, 3));
+    assert_eq!(transposed.rows().collect::<Vec<_>>(), vec![b".@", b".@", b"@."]);
+    assert_eq!(transposed.transpose(), grid);
+}
+
+#[test]
+fn neighbours_and_positions() {
+    let mut grid = Grid::filled(3, 3, 0);
+    for (x, y) in grid.neighbours8(0, 0) {
+        grid[(x, y)] += 1;
+    }
+    for (x, y) in grid.neighbours4(2, 2) {
+        grid[(x, y)] += 10;
+    }
+    assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[0, 1, 0], &[1, 1, 10], &[0, 10, 0]]);
+    assert_eq!(grid.positions().nth(4), Some((1, 1)));
+    assert_eq!(grid.positions().count(), 9);
+}
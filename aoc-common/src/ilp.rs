@@ -0,0 +1,706 @@
+// A small integer linear programming solver: branch and bound, with the simplex method
+// solving the LP relaxation (the same problem, but with fractions allowed) for a lower
+// bound on the cost of every branch. Equations are solved in whole numbers first, so the
+// branches only ever have to choose between their whole number solutions, which keeps it
+// quick even when the numbers are big. Good for the small problems in puzzles, with tens
+// of variables and constraints, not for anything with lots of them. The fractions are
+// exact, no floats anywhere, so nothing depends on how close to a whole number is close
+// enough.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// How the left side of a constraint compares to the right side
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    AtMost,
+    Equal,
+    AtLeast,
+}
+
+/// Find the whole numbers `x >= 0` with the lowest total cost `sum(costs[i] * x[i])`,
+/// where every constraint `sum(coefficients[i] * x[i]) <relation> value` holds
+#[derive(Debug, Clone)]
+pub struct IntegerProgram {
+    costs: Vec<i64>,
+    constraints: Vec<(Vec<i64>, Relation, i64)>,
+    upper: Vec<Option<i64>>, // the highest each variable may go, if it's limited
+}
+
+/// The best values found for the variables, and what they cost in total
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    pub cost: i64,
+    pub values: Vec<i64>,
+}
+
+// What the simplex method found for the LP relaxation
+enum Relaxation {
+    Infeasible,
+    Unbounded,
+    Optimal(Ratio, Vec<Ratio>),
+}
+
+// A branch of branch and bound, with the bounds of the variables in it, and what the
+// relaxation found for it
+struct Branch {
+    bound: i64, // the lowest cost there can be in the branch
+    depth: usize,
+    lower: Vec<i64>,
+    upper: Vec<Option<i64>>,
+    values: Vec<Ratio>,
+}
+
+// The heap puts the greatest first, so that's the lowest bound, and then the deepest
+impl Ord for Branch {
+    fn cmp(&self, other: &Branch) -> Ordering {
+        other.bound.cmp(&self.bound).then(self.depth.cmp(&other.depth))
+    }
+}
+
+impl PartialOrd for Branch {
+    fn partial_cmp(&self, other: &Branch) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Branch {
+    fn eq(&self, other: &Branch) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Branch {}
+
+impl IntegerProgram {
+    /// A problem with one variable for each cost
+    pub fn new(costs: &[i64]) -> Self {
+        IntegerProgram { costs: costs.to_vec(), constraints: Vec::new(), upper: vec![None; costs.len()] }
+    }
+
+    /// Add a constraint, with a coefficient for each variable
+    pub fn constrain(&mut self, coefficients: &[i64], relation: Relation, value: i64) {
+        assert_eq!(coefficients.len(), self.costs.len(), "one coefficient for each variable");
+        // With whole numbers, the left side is always a multiple of what the coefficients have
+        // in common, so an inequality can just as well be about the multiple, rounded the way
+        // it goes. Then there's less room with fractions only for the relaxation.
+        let divisor = coefficients.iter().fold(0, |divisor, &a| gcd(divisor, a));
+        let divided = || coefficients.iter().map(|a| a / divisor).collect();
+        let constraint = match relation {
+            Relation::AtMost if divisor > 1 => (divided(), relation, value.div_euclid(divisor)),
+            Relation::AtLeast if divisor > 1 => (divided(), relation, -(-value).div_euclid(divisor)),
+            _ => (coefficients.to_vec(), relation, value),
+        };
+        self.constraints.push(constraint);
+    }
+
+    /// Keep a variable from going over a maximum. The same as a constraint, but it's
+    /// good to know (and give) the bounds, since they keep the branches small.
+    pub fn at_most(&mut self, variable: usize, max: i64) {
+        self.upper[variable] = Some(self.upper[variable].map_or(max, |upper| upper.min(max)));
+    }
+
+    /// The solution with the lowest cost, or None if there are no solutions at all
+    /// (or no lowest one, when the cost can keep going down forever). Variables that
+    /// can go up forever without changing the cost can keep the branches from ever
+    /// ending, so those are best given a maximum with `at_most`.
+    pub fn minimize(&self) -> Option<Solution> {
+        let equations: Vec<(&[i64], i64)> = self
+            .constraints
+            .iter()
+            .filter(|(_, relation, _)| *relation == Relation::Equal)
+            .map(|(coefficients, _, value)| (coefficients.as_slice(), *value))
+            .collect();
+        if equations.is_empty() {
+            return self.branch_and_bound();
+        }
+
+        // The whole numbers that solve the equations are spread out on a lattice, with a lot of
+        // room in between. Branching on one variable at a time mostly just cuts off some of that
+        // room, one at a time, and with big numbers that goes on pretty much forever. So instead,
+        // the variables become how many of each step along the lattice to take from one of its
+        // points, where every whole number of steps is a solution of the equations.
+        let lattice = Lattice::new(&equations, &self.costs)?;
+
+        // The relaxation tells how far back the solutions go along each step, so the steps can
+        // be counted from there and never be negative. It's the same relaxation every time, just
+        // with different costs, so phase 1 only has to be done once.
+        let mut relaxed = self.relax(&vec![0; self.costs.len()], &self.upper)?;
+        let mut low = Vec::new();
+        for coordinate in &lattice.coordinates {
+            match relaxed.minimize(coordinate) {
+                Relaxation::Optimal(min, _) => low.push(min.ceil()),
+                // Going back forever along a step, but the cost might not, so that's left to the branches
+                Relaxation::Infeasible | Relaxation::Unbounded => return self.branch_and_bound(),
+            }
+        }
+
+        let mut start = lattice.start.clone();
+        for (step, &low) in lattice.steps.iter().zip(&low) {
+            start.iter_mut().zip(step).for_each(|(value, &a)| *value = exact(value.checked_add(exact(a.checked_mul(low)))));
+        }
+        let along = |coefficients: &[i64]| -> Vec<i64> { lattice.steps.iter().map(|step| dot(coefficients, step)).collect() };
+        let mut program = IntegerProgram::new(&along(&self.costs));
+        for (coefficients, relation, value) in &self.constraints {
+            if *relation != Relation::Equal {
+                program.constrain(&along(coefficients), *relation, value - dot(coefficients, &start));
+            }
+        }
+        for i in 0..self.costs.len() {
+            let mut unit = vec![0; self.costs.len()];
+            unit[i] = 1;
+            program.constrain(&along(&unit), Relation::AtLeast, -start[i]);
+            if let Some(upper) = self.upper[i] {
+                program.constrain(&along(&unit), Relation::AtMost, upper - start[i]);
+            }
+        }
+
+        let steps = program.branch_and_bound()?;
+        let mut values = start;
+        for (step, &count) in lattice.steps.iter().zip(&steps.values) {
+            values.iter_mut().zip(step).for_each(|(value, &a)| *value = exact(value.checked_add(exact(a.checked_mul(count)))));
+        }
+        debug_assert!(self.satisfied_by(&values));
+        Some(Solution { cost: dot(&self.costs, &values), values })
+    }
+
+    // Always go on with the branch that has the lowest bound on its cost, since that's where the
+    // best solution might be, until the best solution found is no worse than any bound left. Of
+    // the branches that are just as good, the one that's been branched the most goes first, which
+    // dives down to a solution like depth first would, to have something to beat early on.
+    fn branch_and_bound(&self) -> Option<Solution> {
+        let mut best: Option<Solution> = None;
+
+        // With whole numbers, the cost can only be a multiple of what all of the costs have in
+        // common, so the bounds can be rounded up to the next one of those
+        let multiple = self.costs.iter().fold(0, |multiple, &cost| gcd(multiple, cost)).max(1);
+        let round_up = |cost: Ratio| (cost * Ratio::new(1, multiple)).ceil() * multiple;
+
+        let lower = vec![0; self.costs.len()];
+        let mut branches = BinaryHeap::new();
+        match self.relaxation(&lower, &self.upper) {
+            Relaxation::Infeasible | Relaxation::Unbounded => return None,
+            Relaxation::Optimal(cost, values) => branches.push(Branch { bound: round_up(cost), depth: 0, lower, upper: self.upper.clone(), values }),
+        }
+
+        while let Some(Branch { bound, depth, lower, upper, values }) = branches.pop() {
+            // Nothing in a branch can cost less than its relaxation rounded up. When that's no
+            // better than the best, neither is any other branch.
+            if best.as_ref().is_some_and(|best| bound >= best.cost) {
+                break;
+            }
+
+            // The relaxation rounded to whole numbers might just work, and if they already are
+            // whole numbers it does, and that's the best there is in this branch
+            let rounded: Vec<i64> = values.iter().map(|value| value.round()).collect();
+            if self.satisfied_by(&rounded) {
+                let cost = dot(&self.costs, &rounded);
+                if best.as_ref().is_none_or(|best| cost < best.cost) {
+                    best = Some(Solution { cost, values: rounded });
+                }
+            }
+
+            // Branch on the variable that's the furthest from a whole number: either it's at
+            // most the value rounded down, or at least the value rounded up
+            let branch = (0..values.len())
+                .filter(|&i| !values[i].is_whole())
+                .max_by_key(|&i| values[i].distance_to_whole());
+            let Some(i) = branch else {
+                // All whole numbers already, so nothing more to it than the rounding
+                continue;
+            };
+            let floor = values[i].floor();
+            let mut down = upper.clone();
+            down[i] = Some(floor);
+            let mut up = lower.clone();
+            up[i] = floor + 1;
+            for (lower, upper) in [(lower, down), (up, upper)] {
+                if let Relaxation::Optimal(cost, values) = self.relaxation(&lower, &upper) {
+                    let bound = round_up(cost);
+                    if best.as_ref().is_none_or(|best| bound < best.cost) {
+                        branches.push(Branch { bound, depth: depth + 1, lower, upper, values });
+                    }
+                }
+            }
+        }
+
+        best
+    }
+
+    fn satisfied_by(&self, values: &[i64]) -> bool {
+        let in_bounds = values.iter().zip(&self.upper).all(|(&value, upper)| value >= 0 && upper.is_none_or(|upper| value <= upper));
+        in_bounds
+            && self.constraints.iter().all(|(coefficients, relation, value)| {
+                let total: i64 = coefficients.iter().zip(values).map(|(a, x)| a * x).sum();
+                match relation {
+                    Relation::AtMost => total <= *value,
+                    Relation::Equal => total == *value,
+                    Relation::AtLeast => total >= *value,
+                }
+            })
+    }
+
+    // Solve the problem with fractions allowed, and each variable within its bounds
+    fn relaxation(&self, lower: &[i64], upper: &[Option<i64>]) -> Relaxation {
+        match self.relax(lower, upper) {
+            Some(mut relaxed) => relaxed.minimize(&self.costs),
+            None => Relaxation::Infeasible,
+        }
+    }
+
+    // Phase 1 of the two phase simplex method, for a solution with fractions allowed to start
+    // from, or None if there isn't any. The variables are shifted by their lower bounds first,
+    // so that they all start at 0 like simplex wants, and the upper bounds are just more
+    // constraints.
+    fn relax(&self, lower: &[i64], upper: &[Option<i64>]) -> Option<Relaxed> {
+        let vars = self.costs.len();
+
+        let mut rows: Vec<(Vec<i64>, Relation, i64)> = Vec::new();
+        for (coefficients, relation, value) in &self.constraints {
+            let shift: i64 = coefficients.iter().zip(lower).map(|(a, low)| a * low).sum();
+            rows.push((coefficients.clone(), *relation, value - shift));
+        }
+        for i in 0..vars {
+            if let Some(upper) = upper[i] {
+                if upper < lower[i] {
+                    return None;
+                }
+                let mut coefficients = vec![0; vars];
+                coefficients[i] = 1;
+                rows.push((coefficients, Relation::AtMost, upper - lower[i]));
+            }
+        }
+
+        // Simplex wants the right sides to not be negative, so flip the rows that are
+        for (coefficients, relation, value) in rows.iter_mut() {
+            if *value < 0 {
+                coefficients.iter_mut().for_each(|a| *a = -*a);
+                *value = -*value;
+                *relation = match relation {
+                    Relation::AtMost => Relation::AtLeast,
+                    Relation::Equal => Relation::Equal,
+                    Relation::AtLeast => Relation::AtMost,
+                };
+            }
+        }
+
+        // The columns of the tableau are the variables, then a slack variable for each row
+        // that isn't an equation, then an artificial variable for each row that doesn't
+        // have a slack to start out with in the basis, and the right side at the very end
+        let slacks = rows.iter().filter(|(_, relation, _)| *relation != Relation::Equal).count();
+        let artificials = rows.iter().filter(|(_, relation, _)| *relation != Relation::AtMost).count();
+        let columns = vars + slacks + artificials;
+        let mut tableau = Tableau { rows: Vec::new(), basis: Vec::new(), denominator: 1, reduced: Vec::new() };
+        let (mut slack, mut artificial) = (vars, vars + slacks);
+        for (coefficients, relation, value) in rows {
+            let mut row = coefficients;
+            row.resize(columns + 1, 0);
+            row[columns] = value;
+            match relation {
+                Relation::AtMost => {
+                    row[slack] = 1;
+                    tableau.basis.push(slack);
+                    slack += 1;
+                }
+                Relation::AtLeast => {
+                    row[slack] = -1;
+                    slack += 1;
+                    row[artificial] = 1;
+                    tableau.basis.push(artificial);
+                    artificial += 1;
+                }
+                Relation::Equal => {
+                    row[artificial] = 1;
+                    tableau.basis.push(artificial);
+                    artificial += 1;
+                }
+            }
+            tableau.rows.push(row);
+        }
+
+        // Phase 1: get the artificial variables down to 0, then we have a real solution to start from
+        let mut phase1 = vec![0; columns];
+        phase1[vars + slacks..].iter_mut().for_each(|cost| *cost = 1);
+        tableau.minimize(&phase1, columns);
+        if tableau.value(&phase1) > Ratio::ZERO {
+            return None;
+        }
+        // Any artificial variables still in the basis (at 0) are swapped for real ones where possible.
+        // The rows where that isn't possible were just repeating other rows, so they don't matter.
+        for r in 0..tableau.rows.len() {
+            if tableau.basis[r] >= vars + slacks
+                && let Some(column) = (0..vars + slacks).find(|&c| tableau.rows[r][c] != 0)
+            {
+                tableau.pivot(r, column);
+            }
+        }
+
+        Some(Relaxed { tableau, lower: lower.to_vec(), columns, allowed: vars + slacks })
+    }
+}
+
+// A solution with fractions allowed after phase 1, to go on from for the lowest of any costs
+struct Relaxed {
+    tableau: Tableau,
+    lower: Vec<i64>,
+    columns: usize,
+    allowed: usize, // all but the artificial variables
+}
+
+impl Relaxed {
+    // Phase 2: the costs, and the artificial variables can't come back. The solution stays a
+    // solution, so it's a good start for the next costs too.
+    fn minimize(&mut self, costs: &[i64]) -> Relaxation {
+        let vars = costs.len();
+        let mut phase2 = vec![0; self.columns];
+        phase2[..vars].copy_from_slice(costs);
+        if !self.tableau.minimize(&phase2, self.allowed) {
+            return Relaxation::Unbounded;
+        }
+
+        let mut values: Vec<Ratio> = self.lower.iter().map(|&low| Ratio::whole(low)).collect();
+        for (r, &column) in self.tableau.basis.iter().enumerate() {
+            if column < vars {
+                values[column] = values[column] + Ratio::new(self.tableau.rows[r][self.columns], self.tableau.denominator);
+            }
+        }
+        let cost = costs.iter().zip(&values).fold(Ratio::ZERO, |total, (&cost, &value)| total + Ratio::whole(cost) * value);
+        Relaxation::Optimal(cost, values)
+    }
+}
+
+// All of the whole numbers that solve some equations: a start, plus any whole number of each
+// of the steps. And to go back, how many of each step it is to some solution x from the start,
+// which is coordinates[i] * x.
+struct Lattice {
+    start: Vec<i64>,
+    steps: Vec<Vec<i64>>,
+    coordinates: Vec<Vec<i64>>,
+}
+
+impl Lattice {
+    // None if there are no whole number solutions at all. The columns of the equations are
+    // combined two at a time like in Euclid's algorithm, until each equation has only one
+    // number left for a column that no later one has (the Hermite normal form). All of the
+    // combining can be undone in whole numbers, so a whole number solution for the combined
+    // columns is one for the real variables, and the other way around. The columns that are
+    // left without any numbers don't change anything, which makes them the steps.
+    //
+    // The costs go through the same combining at the end, as if they were one more equation,
+    // but without anything to solve for. That leaves only the first step changing the cost,
+    // and branching on it goes from one cost that whole numbers can have to the next.
+    fn new(equations: &[(&[i64], i64)], costs: &[i64]) -> Option<Lattice> {
+        let vars = costs.len();
+        let mut rows: Vec<Vec<i64>> = equations.iter().map(|(coefficients, _)| coefficients.to_vec()).collect();
+        rows.push(costs.to_vec());
+        // The variables in the combined columns (one row for each variable), and the other way around
+        let mut combined: Vec<Vec<i64>> = (0..vars).map(|i| (0..vars).map(|j| (i == j) as i64).collect()).collect();
+        let mut uncombined = combined.clone();
+
+        let mut solution = vec![0; vars];
+        let mut pivot = 0;
+        for r in 0..rows.len() {
+            for c in pivot + 1..vars {
+                if rows[r][c] == 0 {
+                    continue;
+                }
+                // With p * a + q * b = g, the pivot column becomes p of itself and q of column c,
+                // and column c becomes a / g of itself minus b / g of the pivot column, for g and 0
+                // in this row. Undoing that is the same kind of thing, for the rows of the inverse.
+                let (g, p, q) = extended_gcd(rows[r][pivot], rows[r][c]);
+                let (a, b) = (rows[r][pivot] / g, rows[r][c] / g);
+                for row in rows.iter_mut().chain(combined.iter_mut()) {
+                    (row[pivot], row[c]) = combine((row[pivot], row[c]), (p, q, -b, a));
+                }
+                let (first, second) = uncombined.split_at_mut(c);
+                for (x, y) in first[pivot].iter_mut().zip(second[0].iter_mut()) {
+                    (*x, *y) = combine((*x, *y), (a, b, -q, p));
+                }
+            }
+
+            let Some(&(_, value)) = equations.get(r) else {
+                break;
+            };
+            // What's left of the value, after the columns that earlier equations already solved for
+            let rest = value - dot(&rows[r][..pivot], &solution[..pivot]);
+            if pivot < vars && rows[r][pivot] != 0 {
+                if rest % rows[r][pivot] != 0 {
+                    return None;
+                }
+                solution[pivot] = rest / rows[r][pivot];
+                pivot += 1;
+            } else if rest != 0 {
+                return None;
+            }
+        }
+
+        let start = combined.iter().map(|row| dot(&row[..pivot], &solution[..pivot])).collect();
+        let steps = (pivot..vars).map(|c| combined.iter().map(|row| row[c]).collect()).collect();
+        let coordinates = uncombined[pivot..].to_vec();
+        let mut lattice = Lattice { start, steps, coordinates };
+        lattice.shorten();
+        Some(lattice)
+    }
+
+    // Keep taking whole numbers of one step off another while that makes it shorter, until none
+    // of them get any shorter (which is Gauss' reduction, when there are two). Short steps are
+    // more or less at right angles to each other, so there's no long and thin room between the
+    // whole numbers of steps for the branches to cut up one at a time. The first step is the one
+    // that changes the cost, so it can't be taken off any of the others.
+    fn shorten(&mut self) {
+        let mut shorter = true;
+        while shorter {
+            shorter = false;
+            for i in 0..self.steps.len() {
+                for j in (1..self.steps.len()).filter(|&j| j != i) {
+                    let (along, length) = (dot(&self.steps[i], &self.steps[j]), dot(&self.steps[j], &self.steps[j]));
+                    if 2 * along.abs() <= length {
+                        continue;
+                    }
+                    // Then step i is q of step j less, and there's q more of step j for the same point
+                    let q = Ratio::new(along, length).round();
+                    for k in 0..self.start.len() {
+                        self.steps[i][k] = exact(self.steps[i][k].checked_sub(exact(q.checked_mul(self.steps[j][k]))));
+                        self.coordinates[j][k] = exact(self.coordinates[j][k].checked_add(exact(q.checked_mul(self.coordinates[i][k]))));
+                    }
+                    shorter = true;
+                }
+            }
+        }
+    }
+}
+
+// (p * x + q * y, r * x + s * y)
+fn combine((x, y): (i64, i64), (p, q, r, s): (i64, i64, i64, i64)) -> (i64, i64) {
+    (dot(&[p, q], &[x, y]), dot(&[r, s], &[x, y]))
+}
+
+// The greatest common divisor g of a and b, and p and q so that p * a + q * b = g
+fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    if b == 0 {
+        (a.abs(), a.signum(), 0)
+    } else {
+        let (g, p, q) = extended_gcd(b, a % b);
+        (g, q, p - (a / b) * q)
+    }
+}
+
+fn dot(a: &[i64], b: &[i64]) -> i64 {
+    a.iter().zip(b).fold(0, |total: i64, (&a, &b)| exact(total.checked_add(exact(a.checked_mul(b)))))
+}
+
+// The rows of the constraints, with the right side as the last column, and which variable
+// is in the basis for each row (its value is the right side of the row). All of the numbers
+// are whole, over one common denominator, so there's no need for fractions while pivoting:
+// like with Bareiss' fraction-free elimination, every step divides exactly.
+struct Tableau {
+    rows: Vec<Vec<i64>>,
+    basis: Vec<usize>,
+    denominator: i64, // always positive
+    reduced: Vec<i64>, // how much the cost changes for every 1 each column's variable goes up
+}
+
+impl Tableau {
+    // Pivot until the cost can't go down any further, only bringing the first `allowed`
+    // columns into the basis. False if it can go down forever.
+    fn minimize(&mut self, costs: &[i64], allowed: usize) -> bool {
+        let rhs = costs.len();
+        // The reduced costs for the basis as it is now (and minus the cost so far, in the
+        // right side column). Pivoting keeps them up to date from then on.
+        self.reduced = costs.iter().map(|&cost| exact(cost.checked_mul(self.denominator))).collect();
+        self.reduced.push(0);
+        for (row, &b) in self.rows.iter().zip(&self.basis) {
+            if costs[b] != 0 {
+                self.reduced.iter_mut().zip(row).for_each(|(cost, &value)| *cost = exact(cost.checked_sub(exact(costs[b].checked_mul(value)))));
+            }
+        }
+
+        let mut stuck = false;
+        loop {
+            // The column that lowers the cost the fastest, and the first row of the ones that
+            // limit it the most. When a pivot doesn't lower the cost at all, that can go round
+            // in circles, so then it's Bland's rule, the first column that lowers the cost at all
+            // (and the row with the first variable), which never does.
+            let lowering = (0..allowed).filter(|&c| self.reduced[c] < 0);
+            let column = if stuck { lowering.min() } else { lowering.min_by_key(|&c| self.reduced[c]) };
+            let Some(column) = column else {
+                return true;
+            };
+            let mut best: Option<usize> = None;
+            for (r, row) in self.rows.iter().enumerate() {
+                if row[column] > 0 {
+                    // row[rhs] / row[column] compared to the best one, without dividing
+                    if best.is_none_or(|best| {
+                        let ratio = exact(row[rhs].checked_mul(self.rows[best][column]));
+                        let best_ratio = exact(self.rows[best][rhs].checked_mul(row[column]));
+                        ratio < best_ratio || (ratio == best_ratio && self.basis[r] < self.basis[best])
+                    }) {
+                        best = Some(r);
+                    }
+                }
+            }
+            let Some(r) = best else {
+                return false;
+            };
+            stuck = self.rows[r][rhs] == 0;
+            self.pivot(r, column);
+        }
+    }
+
+    fn value(&self, costs: &[i64]) -> Ratio {
+        let rhs = costs.len();
+        let value = self.rows.iter().zip(&self.basis).map(|(row, &b)| costs[b] * row[rhs]).sum();
+        Ratio::new(value, self.denominator)
+    }
+
+    // Bring the column into the basis in place of row r's variable. The pivot becomes the
+    // new denominator, and the row stays as it is, since that's the row divided by the pivot.
+    fn pivot(&mut self, r: usize, column: usize) {
+        let pivot = self.rows[r][column];
+        let pivot_row = self.rows[r].clone();
+        let denominator = self.denominator;
+        let eliminate = |row: &mut Vec<i64>| {
+            let factor = row[column];
+            for (value, &p) in row.iter_mut().zip(&pivot_row) {
+                let cross = exact(exact(pivot.checked_mul(*value)).checked_sub(exact(factor.checked_mul(p))));
+                debug_assert_eq!(cross % denominator, 0, "fraction-free pivoting always divides exactly");
+                *value = cross / denominator;
+            }
+        };
+        for (other, row) in self.rows.iter_mut().enumerate() {
+            if other != r {
+                eliminate(row);
+            }
+        }
+        if !self.reduced.is_empty() {
+            eliminate(&mut self.reduced);
+        }
+        self.denominator = pivot;
+        self.basis[r] = column;
+
+        // Keep the denominator positive, so the signs of the numbers are the signs of what they stand for
+        if pivot < 0 {
+            self.denominator = -pivot;
+            for row in self.rows.iter_mut().chain([&mut self.reduced]) {
+                row.iter_mut().for_each(|value| *value = -*value);
+            }
+        }
+    }
+}
+
+// An exact fraction, always in its lowest terms and with a positive denominator, so equal
+// fractions are equal numbers. An i64 is plenty for the small problems this is meant for,
+// and if the numbers ever do get too big, it stops instead of carrying on with wrong ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Ratio {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Ratio {
+    const ZERO: Ratio = Ratio { numerator: 0, denominator: 1 };
+    const ONE: Ratio = Ratio { numerator: 1, denominator: 1 };
+
+    fn new(numerator: i64, denominator: i64) -> Self {
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Ratio { numerator: numerator / divisor, denominator: denominator / divisor }
+    }
+
+    fn whole(value: i64) -> Self {
+        Ratio { numerator: value, denominator: 1 }
+    }
+
+    fn is_whole(&self) -> bool {
+        self.denominator == 1
+    }
+
+    fn floor(&self) -> i64 {
+        self.numerator.div_euclid(self.denominator)
+    }
+
+    fn ceil(&self) -> i64 {
+        -(-self.numerator).div_euclid(self.denominator)
+    }
+
+    fn round(&self) -> i64 {
+        (*self + Ratio::new(1, 2)).floor()
+    }
+
+    // How far it is to the nearest whole number, up or down
+    fn distance_to_whole(&self) -> Ratio {
+        let down = *self - Ratio::whole(self.floor());
+        down.min(Ratio::ONE - down)
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn exact(value: Option<i64>) -> i64 {
+    value.expect("the numbers got too big for an i64")
+}
+
+impl Add for Ratio {
+    type Output = Ratio;
+    fn add(self, other: Ratio) -> Ratio {
+        // whole numbers are the most common by far, and they don't need any cancelling
+        if self.denominator == 1 && other.denominator == 1 {
+            return Ratio { numerator: exact(self.numerator.checked_add(other.numerator)), denominator: 1 };
+        }
+        let numerator = exact(self.numerator.checked_mul(other.denominator))
+            .checked_add(exact(other.numerator.checked_mul(self.denominator)));
+        Ratio::new(exact(numerator), exact(self.denominator.checked_mul(other.denominator)))
+    }
+}
+
+impl Sub for Ratio {
+    type Output = Ratio;
+    fn sub(self, other: Ratio) -> Ratio {
+        self + -other
+    }
+}
+
+impl Mul for Ratio {
+    type Output = Ratio;
+    fn mul(self, other: Ratio) -> Ratio {
+        if self.denominator == 1 && other.denominator == 1 {
+            return Ratio { numerator: exact(self.numerator.checked_mul(other.numerator)), denominator: 1 };
+        }
+        // cross cancelling first keeps the numbers smaller
+        let a = gcd(self.numerator, other.denominator);
+        let b = gcd(other.numerator, self.denominator);
+        Ratio::new(
+            exact((self.numerator / a).checked_mul(other.numerator / b)),
+            exact((self.denominator / b).checked_mul(other.denominator / a)),
+        )
+    }
+}
+
+impl Neg for Ratio {
+    type Output = Ratio;
+    fn neg(self) -> Ratio {
+        Ratio { numerator: -self.numerator, denominator: self.denominator }
+    }
+}
+
+impl Ord for Ratio {
+    fn cmp(&self, other: &Ratio) -> Ordering {
+        // the denominators are positive, so multiplying by them keeps the order
+        exact(self.numerator.checked_mul(other.denominator)).cmp(&exact(other.numerator.checked_mul(self.denominator)))
+    }
+}
+
+impl PartialOrd for Ratio {
+    fn partial_cmp(&self, other: &Ratio) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests;
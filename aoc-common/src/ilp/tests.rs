@@ -0,0 +1,177 @@
+use super::*;
+
+#[test]
+fn already_whole_numbers() {
+    // x + y = 4, x - y = 2, for x = 3 and y = 1
+    let mut program = IntegerProgram::new(&[1, 1]);
+    program.constrain(&[1, 1], Relation::Equal, 4);
+    program.constrain(&[1, -1], Relation::Equal, 2);
+    assert_eq!(program.minimize(), Some(Solution { cost: 4, values: vec![3, 1] }));
+}
+
+#[test]
+fn branching_to_whole_numbers() {
+    // 2x + 2y >= 3 is fine with x = 1.5 for the relaxation, but it takes two whole ones
+    let mut program = IntegerProgram::new(&[1, 1]);
+    program.constrain(&[2, 2], Relation::AtLeast, 3);
+    assert_eq!(program.minimize().map(|solution| solution.cost), Some(2));
+
+    // Maximizing with negative costs: 5x + 4y <= 24 and 6x + 4y <= 30 would be
+    // x = 3, y = 2.25 with fractions, but the best whole numbers are x = 4, y = 1
+    let mut program = IntegerProgram::new(&[-3, -2]);
+    program.constrain(&[5, 4], Relation::AtMost, 24);
+    program.constrain(&[6, 4], Relation::AtMost, 30);
+    assert_eq!(program.minimize(), Some(Solution { cost: -14, values: vec![4, 1] }));
+}
+
+#[test]
+fn bounds_on_variables() {
+    let mut program = IntegerProgram::new(&[1, 3]);
+    program.constrain(&[1, 1], Relation::Equal, 10);
+    program.at_most(0, 7);
+    assert_eq!(program.minimize(), Some(Solution { cost: 16, values: vec![7, 3] }));
+
+    // the lowest bound is the one that counts
+    program.at_most(0, 4);
+    program.at_most(0, 6);
+    assert_eq!(program.minimize(), Some(Solution { cost: 22, values: vec![4, 6] }));
+}
+
+#[test]
+fn no_solutions() {
+    // Only even totals with 2x + 4y
+    let mut program = IntegerProgram::new(&[1, 1]);
+    program.constrain(&[2, 4], Relation::Equal, 7);
+    assert_eq!(program.minimize(), None);
+
+    // Not even with fractions
+    let mut program = IntegerProgram::new(&[1]);
+    program.constrain(&[1], Relation::AtLeast, 5);
+    program.at_most(0, 4);
+    assert_eq!(program.minimize(), None);
+}
+
+#[test]
+fn no_lowest_cost() {
+    let mut program = IntegerProgram::new(&[-1, 0]);
+    program.constrain(&[1, -1], Relation::Equal, 0);
+    assert_eq!(program.minimize(), None);
+}
+
+#[test]
+fn repeated_constraints() {
+    // The same equation twice leaves an artificial variable in the basis after phase 1
+    let mut program = IntegerProgram::new(&[2, 1]);
+    program.constrain(&[1, 1], Relation::Equal, 5);
+    program.constrain(&[2, 2], Relation::Equal, 10);
+    assert_eq!(program.minimize(), Some(Solution { cost: 5, values: vec![0, 5] }));
+}
+
+#[test]
+fn no_rounding_errors() {
+    // With fractions, x = 1 / 1000000007 and y = 0 is the best there is, which is as good as
+    // whole numbers for floats. The only whole solutions have x and y at least 1.
+    let mut program = IntegerProgram::new(&[1, 1]);
+    program.constrain(&[1_000_000_007, -1_000_000_006], Relation::Equal, 1);
+    assert_eq!(program.minimize(), Some(Solution { cost: 2, values: vec![1, 1] }));
+}
+
+#[test]
+fn whole_numbers_on_the_lattice() {
+    // x + y + z = 5 and x - y = 1, so x = y + 1 and z = 4 - 2y: one step, of (1, 1, -2)
+    let equations: [(&[i64], i64); 2] = [(&[1, 1, 1], 5), (&[1, -1, 0], 1)];
+    let lattice = Lattice::new(&equations, &[1, 1, 1]).unwrap();
+    assert_eq!(lattice.steps.len(), 1);
+    assert!(lattice.steps[0] == [1, 1, -2] || lattice.steps[0] == [-1, -1, 2], "{:?}", lattice.steps);
+    for count in -3..3 {
+        let x: Vec<i64> = lattice.start.iter().zip(&lattice.steps[0]).map(|(start, step)| start + count * step).collect();
+        assert_eq!((x[0] + x[1] + x[2], x[0] - x[1]), (5, 1));
+        assert_eq!(dot(&lattice.coordinates[0], &x), count);
+    }
+
+    // 2x + 4y is always even, and 3x + 3y a multiple of 3
+    assert!(Lattice::new(&[(&[2, 4], 7)], &[1, 1]).is_none());
+    assert!(Lattice::new(&[(&[1, 1], 2), (&[3, 3], 7)], &[1, 1]).is_none());
+}
+
+#[test]
+fn inequalities_on_multiples() {
+    // 2x + 2y >= 3 is x + y >= 2 in whole numbers, and 3x - 6y <= 4 is x - 2y <= 1
+    let mut program = IntegerProgram::new(&[1, 1]);
+    program.constrain(&[2, 2], Relation::AtLeast, 3);
+    program.constrain(&[3, -6], Relation::AtMost, 4);
+    assert_eq!(program.constraints, [(vec![1, 1], Relation::AtLeast, 2), (vec![1, -2], Relation::AtMost, 1)]);
+    assert_eq!(program.minimize().map(|solution| solution.cost), Some(2));
+}
+
+// Every counter is the sum of the buttons wired to it, like the joltages of day 10
+fn counters(buttons: &[&[usize]], counters: &[i64]) -> IntegerProgram {
+    let mut program = IntegerProgram::new(&vec![1; buttons.len()]);
+    for (counter, &value) in counters.iter().enumerate() {
+        let wired: Vec<i64> = buttons.iter().map(|wiring| wiring.contains(&counter) as i64).collect();
+        program.constrain(&wired, Relation::Equal, value);
+    }
+    for (button, wiring) in buttons.iter().enumerate() {
+        program.at_most(button, wiring.iter().map(|&counter| counters[counter]).min().unwrap());
+    }
+    program
+}
+
+#[test]
+fn big_values() {
+    // Branching on the variables one at a time, there's no end in sight with numbers like these
+    let buttons: [&[usize]; 11] = [
+        &[1, 2, 5, 8],
+        &[1, 4, 6, 7, 8],
+        &[4, 8],
+        &[0, 2, 5, 7, 8],
+        &[0, 1, 2, 3, 4, 6, 7, 8],
+        &[2, 4, 7, 8],
+        &[0, 1, 2, 3, 4, 5, 6, 7, 8],
+        &[0, 2, 5, 6],
+        &[0, 1, 2, 6, 8],
+        &[4, 6],
+        &[1, 2, 3, 4, 5, 7, 8],
+    ];
+    let program = counters(&buttons, &[12378, 16840, 21334, 5542, 18241, 13282, 15710, 14079, 26207]);
+    let solution = program.minimize().unwrap();
+    assert_eq!(solution.cost, 29376);
+    assert!(program.satisfied_by(&solution.values));
+
+    // Here the relaxation is at 122784.5, but no whole numbers cost less than 122786 (and the
+    // last counter is just like the first)
+    let buttons: [&[usize]; 8] = [&[0, 1, 3, 4, 5, 6], &[1, 3], &[0, 1, 2, 4, 5, 6], &[0, 6], &[0, 2, 3, 4, 6], &[1, 4], &[0, 1, 2, 4, 6], &[1, 3]];
+    let program = counters(&buttons, &[68037, 83179, 36107, 78990, 77977, 19479, 68037]);
+    let solution = program.minimize().unwrap();
+    assert_eq!(solution.cost, 122786);
+    assert!(program.satisfied_by(&solution.values));
+}
+
+#[test]
+fn exact_fractions() {
+    let third = Ratio::new(2, 6);
+    assert_eq!(third, Ratio::new(-1, -3));
+    assert_eq!(third + third + third, Ratio::ONE);
+    assert_eq!(third - Ratio::ONE, Ratio::new(2, -3));
+    assert_eq!(third * Ratio::new(3, 2), Ratio::new(1, 2));
+    assert!(Ratio::new(-7, 2) < Ratio::whole(-3));
+    assert_eq!((Ratio::new(-7, 2).floor(), Ratio::new(-7, 2).ceil()), (-4, -3));
+    assert_eq!(Ratio::new(7, 4).distance_to_whole(), Ratio::new(1, 4));
+}
+
+#[test]
+fn scratch_big() {
+    let buttons: [&[usize]; 11] = [&[1,2,5,8], &[1,4,6,7,8], &[4,8], &[0,2,5,7,8], &[0,1,2,3,4,6,7,8], &[2,4,7,8], &[0,1,2,3,4,5,6,7,8], &[0,2,5,6], &[0,1,2,6,8], &[4,6], &[1,2,3,4,5,7,8]];
+    let targets = [12378,16840,21334,5542,18241,13282,15710,14079,26207i64];
+    let mut program = IntegerProgram::new(&[1; 11]);
+    for c in 0..9 {
+        let row: Vec<i64> = buttons.iter().map(|b| b.contains(&c) as i64).collect();
+        program.constrain(&row, Relation::Equal, targets[c]);
+    }
+    for (i, b) in buttons.iter().enumerate() {
+        program.at_most(i, b.iter().map(|&c| targets[c]).min().unwrap());
+    }
+    let start = std::time::Instant::now();
+    let solution = program.minimize();
+    eprintln!("{:?} in {:?}", solution, start.elapsed());
+}
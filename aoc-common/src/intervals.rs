@@ -0,0 +1,32 @@
+use std::ops::Range;
+
+/// Sort ranges and merge the ones that are overlapping, bordering or contained
+/// in each other, so every value is in at most one of the merged ranges
+pub fn merge<T: Ord + Copy>(mut ranges: Vec<Range<T>>) -> Vec<Range<T>> {
+    ranges.sort_by_key(|r| r.start);
+
+    let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        // Empty ranges have nothing to merge
+        if range.is_empty() {
+            continue;
+        }
+        match merged.last_mut() {
+            // Overlapping or bordering - we extend the previous, unless it's contained
+            Some(prev) if range.start <= prev.end => prev.end = prev.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+/// Is the value in any of the merged ranges, from `merge`
+pub fn contains<T: Ord>(merged: &[Range<T>], value: &T) -> bool {
+    // The first range that doesn't end before the value is the only one it can be in
+    let i = merged.partition_point(|r| r.end <= *value);
+    merged.get(i).is_some_and(|r| r.contains(value))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,20 @@
+use super::*;
+
+#[test]
+fn merge_overlapping_bordering_and_contained() {
+    let merged = merge(vec![10..15, 3..6, 16..21, 12..19, 1..3, 30..31, 17..18]);
+    assert_eq!(merged, vec![1..6, 10..21, 30..31]);
+}
+
+#[test]
+fn merge_skips_empty_ranges() {
+    assert_eq!(merge(vec![5..5, 1..2]), vec![1..2]);
+    assert_eq!(merge::<u64>(vec![]), vec![]);
+}
+
+#[test]
+fn contains_in_merged() {
+    let merged = merge(vec![3..6, 10..21]);
+    let inside: Vec<u64> = (0..25).filter(|v| contains(&merged, v)).collect();
+    assert_eq!(inside, [3, 4, 5].into_iter().chain(10..21).collect::<Vec<u64>>());
+}
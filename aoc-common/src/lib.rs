@@ -0,0 +1,75 @@
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, BufRead};
+
+pub mod bitgrid;
+pub mod graph;
+pub mod grid;
+pub mod ilp;
+pub mod intervals;
+pub mod options;
+mod parse;
+pub mod timing;
+
+pub use options::Options;
+pub use parse::{InputError, Line, ParseError, lines};
+
+/// The input "file" meaning stdin, so the input can be piped in from other tools
+pub const STDIN: &str = "-";
+
+/// Read all of the puzzle input from a file, or from stdin if the file is "-"
+pub fn read_source(file: &str) -> io::Result<String> {
+    if file == STDIN {
+        read_all(io::stdin().lock())
+    } else {
+        fs::read_to_string(file)
+    }
+}
+
+fn read_all(mut reader: impl BufRead) -> io::Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+/// One day of puzzles. Every day implements this, so the runner (and anything
+/// else, really) can treat all of the days the same way.
+///
+/// Answers are compared using their plain `Display` output. The alternate form
+/// (`{:#}`) may add some extra details, which the runner shows. Those go on the
+/// same line, unless they were asked for with an option, like a drawing of the answer.
+pub trait Day: Sized {
+    /// The day number, used for pointing out where any input errors are
+    const DAY: u8;
+
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Parse the puzzle input text into whatever the day needs to solve the parts.
+    /// This is the one to use for inline test inputs.
+    fn parse(input: &str) -> Result<Self, ParseError>;
+
+    /// The parts only take `&self`, so they can be run in any order and any
+    /// number of times. Any state needed while solving is kept in the part itself.
+    fn part1(&self) -> Self::Answer1;
+    fn part2(&self) -> Self::Answer2;
+
+    /// Take any options for this day out of the ones given on the command line,
+    /// like which algorithm to use. The runner reports whatever is left as unknown.
+    fn configure(&mut self, _options: &mut Options) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Read and parse the puzzle input from anything readable, like stdin or a byte slice
+    fn read(reader: impl BufRead) -> Result<Self, InputError> {
+        Ok(Self::parse(&read_all(reader)?)?)
+    }
+
+    /// Read and parse the puzzle input from a file, or from stdin if the file is "-"
+    fn read_input(file: &str) -> Result<Self, InputError> {
+        Ok(Self::parse(&read_source(file)?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests;
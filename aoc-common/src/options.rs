@@ -0,0 +1,67 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Options for one specific day, given on the command line after the day, like
+/// `aoc run 12 --backend dlx`. Each day takes the ones it knows about in
+/// `Day::configure`, and whatever is left over is an unknown option.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    options: Vec<(String, Option<String>)>, // the name without the dashes, and the value if any
+}
+
+impl Options {
+    pub fn new() -> Self {
+        Options::default()
+    }
+
+    /// Add an option, `--name value` or just a `--name` flag without a value
+    pub fn add(&mut self, name: &str, value: Option<&str>) {
+        self.options.push((name.to_string(), value.map(String::from)));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.options.is_empty()
+    }
+
+    /// Take a flag out of the options, true if it was given
+    pub fn flag(&mut self, name: &str) -> Result<bool, String> {
+        match self.take(name) {
+            Some(None) => Ok(true),
+            Some(Some(value)) => Err(format!("--{} doesn't take a value, but got '{}'", name, value)),
+            None => Ok(false),
+        }
+    }
+
+    /// Take an option with a value out of the options, parsed into whatever it should be
+    pub fn value<T>(&mut self, name: &str) -> Result<Option<T>, String>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.take(name) {
+            Some(Some(value)) => match value.parse() {
+                Ok(value) => Ok(Some(value)),
+                Err(e) => Err(format!("Invalid value '{}' for --{} ({})", value, name, e)),
+            },
+            Some(None) => Err(format!("--{} needs a value", name)),
+            None => Ok(None),
+        }
+    }
+
+    /// The names of the options that nobody took, so they can be reported
+    pub fn remaining(&self) -> impl Iterator<Item = &str> {
+        self.options.iter().map(|(name, _)| name.as_str())
+    }
+
+    // The last one wins if an option is given more than once, like with most tools
+    fn take(&mut self, name: &str) -> Option<Option<String>> {
+        let mut taken = None;
+        while let Some(i) = self.options.iter().position(|(other, _)| other == name) {
+            taken = Some(self.options.remove(i).1);
+        }
+        taken
+    }
+}
+
+#[cfg(test)]
+mod tests;
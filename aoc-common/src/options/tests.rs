@@ -0,0 +1,36 @@
+use super::*;
+
+fn options(args: &[(&str, Option<&str>)]) -> Options {
+    let mut options = Options::new();
+    for &(name, value) in args {
+        options.add(name, value);
+    }
+    options
+}
+
+#[test]
+fn flags_and_values() {
+    let mut options = options(&[("render", None), ("jobs", Some("4")), ("backend", Some("dlx"))]);
+    assert_eq!(options.flag("render"), Ok(true));
+    assert_eq!(options.flag("explain"), Ok(false));
+    assert_eq!(options.value::<usize>("jobs"), Ok(Some(4)));
+    assert_eq!(options.value::<usize>("limit"), Ok(None));
+    assert_eq!(options.remaining().collect::<Vec<_>>(), vec!["backend"]);
+    assert_eq!(options.value::<String>("backend"), Ok(Some("dlx".to_string())));
+    assert!(options.is_empty());
+}
+
+#[test]
+fn the_last_one_wins() {
+    let mut options = options(&[("jobs", Some("4")), ("jobs", Some("2"))]);
+    assert_eq!(options.value::<usize>("jobs"), Ok(Some(2)));
+    assert!(options.is_empty());
+}
+
+#[test]
+fn invalid_options() {
+    assert!(options(&[("render", Some("yes"))]).flag("render").is_err());
+    assert!(options(&[("jobs", None)]).value::<usize>("jobs").is_err());
+    let error = options(&[("jobs", Some("many"))]).value::<usize>("jobs").unwrap_err();
+    assert!(error.starts_with("Invalid value 'many' for --jobs"), "{}", error);
+}
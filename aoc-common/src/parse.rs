@@ -0,0 +1,132 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+/// Something wrong with the puzzle input, pointing out exactly where
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,    // 1-based, like in any editor
+    pub column: usize,  // 1-based, in characters
+    pub text: String,   // the offending part of the line
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(day: u8, line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            day,
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "day {:02}, line {}, column {}", self.day, self.line, self.column)?;
+        if !self.text.is_empty() {
+            write!(f, ", at '{}'", self.text)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Reading the puzzle input can go wrong in two ways, either we can't read it
+/// at all, or we can read it but it doesn't make sense
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Io(e) => write!(f, "{}", e),
+            InputError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io(e) => Some(e),
+            InputError::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for InputError {
+    fn from(e: io::Error) -> Self {
+        InputError::Io(e)
+    }
+}
+
+impl From<ParseError> for InputError {
+    fn from(e: ParseError) -> Self {
+        InputError::Parse(e)
+    }
+}
+
+/// One line of the puzzle input, knowing where it is so it can make good errors
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub day: u8,
+    pub number: usize,  // 1-based
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// An error about some part of this line. The part should be a slice of the
+    /// line text, so we can tell the column (otherwise it's reported as column 1).
+    pub fn error(&self, part: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.day, self.number, self.column_of(part), part, message)
+    }
+
+    /// The 1-based character column where the part of the line starts
+    pub fn column_of(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        match self.text.get(..offset) {
+            Some(before) if offset + part.len() <= self.text.len() => before.chars().count() + 1,
+            _ => 1,
+        }
+    }
+
+    /// Parse some part of the line, with an error pointing at it if that fails
+    pub fn parse<T>(&self, part: &str, what: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        part.parse()
+            .map_err(|e| self.error(part, format!("invalid {} ({})", what, e)))
+    }
+
+    /// Parse a list like "1,2,3" in some part of the line, pointing at the bad item if any
+    pub fn parse_list<T>(&self, part: &str, separator: char, what: &str) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        part.split(separator).map(|item| self.parse(item.trim(), what)).collect()
+    }
+}
+
+/// The lines of the puzzle input, numbered for error reporting
+pub fn lines(day: u8, input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(move |(i, text)| Line { day, number: i + 1, text })
+}
+
+#[cfg(test)]
+mod tests;
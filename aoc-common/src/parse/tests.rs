@@ -0,0 +1,52 @@
+use super::*;
+
+#[test]
+fn lines_are_numbered_from_one() {
+    let numbers: Vec<(usize, &str)> = lines(1, "a\nb\n\nc\n").map(|l| (l.number, l.text)).collect();
+    assert_eq!(numbers, vec![(1, "a"), (2, "b"), (3, ""), (4, "c")]);
+}
+
+#[test]
+fn error_points_at_the_part() {
+    let line = lines(8, "1,2,3\n12,x5,7").nth(1).unwrap();
+    let part = line.text.split(',').nth(1).unwrap();
+    let error = line.error(part, "not a number");
+    assert_eq!(error, ParseError::new(8, 2, 4, "x5", "not a number"));
+    assert_eq!(error.to_string(), "day 08, line 2, column 4, at 'x5': not a number");
+}
+
+#[test]
+fn column_counts_characters() {
+    let line = Line { day: 1, number: 1, text: "ö: abc" };
+    assert_eq!(line.column_of(&line.text[4..]), 4);
+    // Not a part of the line at all (a literal "abc" could end up sharing the memory...)
+    let elsewhere = String::from("abc");
+    assert_eq!(line.column_of(&elsewhere), 1);
+}
+
+#[test]
+fn parse_part_of_line() {
+    let line = Line { day: 2, number: 7, text: "11-x2" };
+    let (start, end) = line.text.split_once('-').unwrap();
+    assert_eq!(line.parse::<u64>(start, "range start"), Ok(11));
+
+    let error = line.parse::<u64>(end, "range end").unwrap_err();
+    assert_eq!((error.line, error.column, error.text.as_str()), (7, 4, "x2"));
+    assert_eq!(error.message, "invalid range end (invalid digit found in string)");
+}
+
+#[test]
+fn error_without_text() {
+    let error = ParseError::new(7, 1, 1, "", "no start");
+    assert_eq!(error.to_string(), "day 07, line 1, column 1: no start");
+}
+
+#[test]
+fn parse_list_of_numbers() {
+    let line = Line { day: 8, number: 3, text: "162,817, 812" };
+    assert_eq!(line.parse_list::<u64>(line.text, ',', "coordinate"), Ok(vec![162, 817, 812]));
+
+    let line = Line { day: 8, number: 3, text: "162,8x7,812" };
+    let error = line.parse_list::<u64>(line.text, ',', "coordinate").unwrap_err();
+    assert_eq!((error.column, error.text.as_str()), (5, "8x7"));
+}
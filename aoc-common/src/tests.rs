@@ -0,0 +1,54 @@
+use super::*;
+
+// Just counting the numbers, and adding them up
+struct Numbers(Vec<u32>);
+
+impl Day for Numbers {
+    const DAY: u8 = 99;
+
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        lines(Self::DAY, input)
+            .map(|line| line.parse(line.text, "number"))
+            .collect::<Result<_, _>>()
+            .map(Numbers)
+    }
+
+    fn part1(&self) -> usize {
+        self.0.len()
+    }
+
+    fn part2(&self) -> u32 {
+        self.0.iter().sum()
+    }
+}
+
+#[test]
+fn read_from_a_reader() {
+    let numbers = Numbers::read("1\n2\n3\n".as_bytes()).unwrap();
+    assert_eq!((numbers.part1(), numbers.part2()), (3, 6));
+}
+
+#[test]
+fn read_errors_are_parse_errors() {
+    match Numbers::read("1\ntwo\n".as_bytes()) {
+        Err(InputError::Parse(error)) => assert_eq!((error.day, error.line, error.text.as_str()), (99, 2, "two")),
+        _ => panic!("expected a parse error"),
+    }
+}
+
+#[test]
+fn read_missing_file() {
+    assert!(matches!(Numbers::read_input("no/such/input.txt"), Err(InputError::Io(_))));
+}
+
+#[test]
+fn no_options_by_default() {
+    let mut numbers = Numbers::parse("1\n").unwrap();
+    let mut options = Options::new();
+    options.add("jobs", Some("4"));
+    assert_eq!(numbers.configure(&mut options), Ok(()));
+    assert_eq!(options.remaining().collect::<Vec<_>>(), vec!["jobs"]);
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// Untimed runs before the measuring starts, to get caches, allocators and
+// whatnots warmed up so the first measurements aren't way off
+const WARMUP_ITERATIONS: usize = 3;
+
+/// Timing stats over a number of runs of something
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "we need at least one sample for any stats");
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        // Let's do the mean and standard deviation in f64 nanoseconds
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "min {:?}, median {:?}, mean {:?}, stddev {:?}",
+            self.min, self.median, self.mean, self.stddev
+        )
+    }
+}
+
+/// Run something a number of times after warming up, and get the timing stats
+/// together with the result of the last run
+pub fn measure<T>(iterations: usize, mut run: impl FnMut() -> T) -> (Stats, T) {
+    for _ in 0..WARMUP_ITERATIONS {
+        black_box(run());
+    }
+
+    let mut samples = Vec::with_capacity(iterations);
+    let mut result = None;
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        let value = black_box(run());
+        samples.push(start.elapsed());
+        result = Some(value);
+    }
+
+    // There's always at least one run, so there's always a result
+    (Stats::from_samples(&samples), result.unwrap())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,40 @@
+use super::*;
+
+fn micros(values: &[u64]) -> Vec<Duration> {
+    values.iter().map(|&v| Duration::from_micros(v)).collect()
+}
+
+#[test]
+fn stats_odd_number_of_samples() {
+    let stats = Stats::from_samples(&micros(&[5, 1, 3]));
+    assert_eq!(stats.min, Duration::from_micros(1));
+    assert_eq!(stats.median, Duration::from_micros(3));
+    assert_eq!(stats.mean, Duration::from_micros(3));
+    assert_eq!(stats.stddev, Duration::from_micros(2));
+}
+
+#[test]
+fn stats_even_number_of_samples() {
+    let stats = Stats::from_samples(&micros(&[4, 1, 2, 9]));
+    assert_eq!(stats.min, Duration::from_micros(1));
+    assert_eq!(stats.median, Duration::from_micros(3));
+    assert_eq!(stats.mean, Duration::from_micros(4));
+}
+
+#[test]
+fn stats_single_sample() {
+    let stats = Stats::from_samples(&micros(&[7]));
+    assert_eq!(stats.median, Duration::from_micros(7));
+    assert_eq!(stats.stddev, Duration::ZERO);
+}
+
+#[test]
+fn measure_returns_last_result() {
+    let mut calls = 0;
+    let (_, result) = measure(5, || {
+        calls += 1;
+        calls
+    });
+    assert_eq!(calls, WARMUP_ITERATIONS + 5);
+    assert_eq!(result, calls);
+}
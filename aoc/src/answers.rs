@@ -0,0 +1,99 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+// The known correct answers for an input, stored in a tiny TOML file like:
+//    part1 = 1234
+//    part2 = "or some text"
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    // By default the answers live in answers.toml, next to the input file
+    pub fn default_file(input: &str) -> String {
+        Path::new(input)
+            .with_file_name("answers.toml")
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    pub fn read(file: &str) -> Result<Self, Box<dyn Error>> {
+        let content = fs::read_to_string(file)?;
+        Ok(Self::parse(&content)?)
+    }
+
+    // We only need a very small part of TOML here, so no need for a crate:
+    // 'key = value' lines, where value is a bare number or a "quoted string",
+    // plus blank lines and # comments
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let mut answers = Answers::default();
+
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected 'key = value'", i + 1))?;
+            let value = parse_value(value.trim())
+                .ok_or_else(|| format!("line {}: invalid value '{}'", i + 1, value.trim()))?;
+
+            match key.trim() {
+                "part1" => answers.part1 = Some(value),
+                "part2" => answers.part2 = Some(value),
+                other => return Err(format!("line {}: unknown key '{}'", i + 1, other)),
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+// Compare an answer to the expected one (if we have one), returns false only if it's wrong
+pub fn check(answer: &str, expected: Option<&str>) -> bool {
+    match expected {
+        Some(expected) if expected == answer => {
+            println!("        check: ok");
+            true
+        }
+        Some(expected) => {
+            println!("        check: WRONG, expected {}", expected);
+            false
+        }
+        None => {
+            println!("        check: no answer recorded");
+            true
+        }
+    }
+}
+
+fn parse_value(value: &str) -> Option<String> {
+    let (value, rest) = match value.strip_prefix('"') {
+        // A quoted string goes until the next quote (no escapes needed for our answers)
+        Some(quoted) => quoted.split_once('"')?,
+        // Otherwise a bare value like a number, until whitespace or a comment
+        None => {
+            let end = value.find(|c: char| c.is_whitespace() || c == '#').unwrap_or(value.len());
+            if end == 0 {
+                return None;
+            }
+            value.split_at(end)
+        }
+    };
+
+    // Only a comment is allowed after the value
+    let rest = rest.trim();
+    if rest.is_empty() || rest.starts_with('#') {
+        Some(value.to_string())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests;
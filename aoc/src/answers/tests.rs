@@ -0,0 +1,30 @@
+use super::*;
+
+#[test]
+fn parse_numbers_and_strings() {
+    let answers = Answers::parse("part1 = 1234\npart2 = \"There is no part 2\"\n").unwrap();
+    assert_eq!(answers.part1.as_deref(), Some("1234"));
+    assert_eq!(answers.part2.as_deref(), Some("There is no part 2"));
+}
+
+#[test]
+fn parse_comments_and_missing_parts() {
+    let answers = Answers::parse("# day 12\n\npart1 = 42 # checked by hand\n").unwrap();
+    assert_eq!(answers.part1.as_deref(), Some("42"));
+    assert_eq!(answers.part2, None);
+}
+
+#[test]
+fn parse_errors() {
+    assert!(Answers::parse("part1 1234").is_err());
+    assert!(Answers::parse("part3 = 1").is_err());
+    assert!(Answers::parse("part1 = \"unterminated").is_err());
+    assert!(Answers::parse("part1 = 12 34").is_err());
+    assert!(Answers::parse("part1 =").is_err());
+}
+
+#[test]
+fn default_file_is_next_to_input() {
+    assert_eq!(Answers::default_file("day07/input.txt"), "day07/answers.toml");
+    assert_eq!(Answers::default_file("input.txt"), "answers.toml");
+}
@@ -0,0 +1,408 @@
+use aoc_common::{Day, Options, STDIN};
+use aoc_common::timing::{self, Stats};
+use answers::Answers;
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::process::ExitCode;
+use std::time::Instant;
+
+mod answers;
+mod readme;
+mod scaffold;
+
+// The days we have solutions for, and can dispatch to below (aoc new adds to both)
+const DAYS: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+// Enough to get some stable numbers for the README, without taking forever
+const README_ITERATIONS: usize = 10;
+
+const USAGE: &str = "Usage: aoc run [DAY [DAY OPTIONS]] [--input PATH] [--check [--answers PATH]] [--bench N]
+       aoc readme [--bench N] [--readme PATH]
+       aoc new dayNN
+
+Runs part 1 and part 2 of the given day, or of all days if no day is given.
+Input defaults to dayNN/input.txt, relative to the current directory, and --input -
+reads it from stdin instead.
+
+  --check          Compare the answers to the ones stored in answers.toml next to the
+                   input file, and exit with an error if any of them are wrong
+  --answers PATH   Use another answers file for --check (needed with input from stdin)
+  --bench N        Measure parsing and each part over N iterations (after a short warmup)
+                   and report min, median, mean and standard deviation
+
+Any other --name or --name VALUE after the day is an option for that day, like
+aoc run 12 --backend dlx. Each day lists its options in its README section.
+
+The readme command benchmarks all of the days on their inputs (10 iterations unless
+given with --bench) and rewrites the results section of README.md (or --readme PATH).
+
+The new command creates a new day from template_day, with empty input.txt, test.txt and
+answers.toml, and adds it to the workspace and to this runner. Run it from the workspace root.";
+
+enum Command {
+    Run(Args),
+    Readme { iterations: usize, readme: String },
+    New { name: String },
+}
+
+struct Args {
+    days: Vec<u8>,
+    input: Option<String>,
+    check: bool,
+    answers: Option<String>,
+    bench: Option<usize>,
+    options: Options, // for the day, only with a specific day
+}
+
+// Everything needed to run one of the days
+struct Job {
+    filename: String,
+    bench: Option<usize>,
+    answers: Option<Answers>,
+    options: Options,
+}
+
+// What came out of running one of the days
+struct Outcome {
+    correct: bool,             // false if any of the checked answers were wrong
+    timings: Option<Timings>,  // only when benchmarking
+}
+
+// The timing stats for one day
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Timings {
+    parse: Stats,
+    part1: Stats,
+    part2: Stats,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let all_good = match parse_args(&args) {
+        Ok(Command::Run(args)) => run_days(&args),
+        Ok(Command::Readme { iterations, readme }) => update_readme(iterations, &readme),
+        Ok(Command::New { name }) => new_day(&name),
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if all_good {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn default_input(day: u8) -> String {
+    format!("day{:02}/input.txt", day)
+}
+
+fn print_day_header(day: u8, bench: Option<usize>) {
+    match bench {
+        Some(iterations) => println!("Day {:02} ({} iterations)", day, iterations),
+        None => println!("Day {:02}", day),
+    }
+}
+
+fn run_days(args: &Args) -> bool {
+    let mut all_good = true;
+    for (i, &day) in args.days.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        let filename = match &args.input {
+            Some(file) => file.clone(),
+            None => default_input(day),
+        };
+
+        print_day_header(day, args.bench);
+
+        // When checking, we need the answers before running anything
+        let answers = if args.check {
+            let answers_file = match &args.answers {
+                Some(file) => file.clone(),
+                None => Answers::default_file(&filename),
+            };
+            match Answers::read(&answers_file) {
+                Ok(answers) => Some(answers),
+                Err(e) => {
+                    eprintln!("Error reading answers {}: {}", answers_file, e);
+                    all_good = false;
+                    continue;
+                }
+            }
+        } else {
+            None
+        };
+
+        let job = Job {
+            filename,
+            bench: args.bench,
+            answers,
+            options: args.options.clone(),
+        };
+
+        match run_day(day, &job) {
+            Ok(outcome) => all_good &= outcome.correct,
+            Err(e) => {
+                eprintln!("Error running day {:02} with input {}: {}", day, job.filename, e);
+                all_good = false;
+            }
+        }
+    }
+
+    all_good
+}
+
+// Benchmark all of the days and put the results in the README
+fn update_readme(iterations: usize, readme_file: &str) -> bool {
+    let mut results = Vec::new();
+
+    for (i, &day) in DAYS.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        print_day_header(day, Some(iterations));
+
+        let job = Job {
+            filename: default_input(day),
+            bench: Some(iterations),
+            answers: None,
+            options: Options::new(),
+        };
+
+        match run_day(day, &job) {
+            Ok(Outcome { timings: Some(timings), .. }) => results.push((day, timings)),
+            Ok(_) => unreachable!("benchmarking always gives timings"),
+            Err(e) => {
+                // Half of a results table would just be confusing, so let's not write anything
+                eprintln!("Error reading input {}: {}", job.filename, e);
+                eprintln!("Not updating {} without results for all of the days", readme_file);
+                return false;
+            }
+        }
+    }
+
+    match readme::update(readme_file, iterations, &results) {
+        Ok(()) => {
+            println!("\nUpdated the results in {}", readme_file);
+            true
+        }
+        Err(e) => {
+            eprintln!("Error updating {}: {}", readme_file, e);
+            false
+        }
+    }
+}
+
+fn new_day(name: &str) -> bool {
+    match scaffold::new_day(name) {
+        Ok(()) => {
+            println!("Created {} from template_day, and added it to the workspace and the runner", name);
+            println!("Put the example in {}/test.txt, and its answers in {}/src/tests.rs", name, name);
+            true
+        }
+        Err(e) => {
+            eprintln!("Error creating {}: {}", name, e);
+            false
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => Ok(Command::Run(parse_run_args(&args[1..])?)),
+        Some("readme") => parse_readme_args(&args[1..]),
+        Some("new") => match &args[1..] {
+            [name] => Ok(Command::New { name: name.clone() }),
+            _ => Err("new needs just the name of the day, like day13".to_string()),
+        },
+        Some(other) => Err(format!("Unknown command '{}'", other)),
+        None => Err("Missing command".to_string()),
+    }
+}
+
+fn parse_iterations(value: Option<&String>) -> Result<usize, String> {
+    let iterations = value.ok_or("--bench needs a number of iterations")?;
+    match iterations.parse() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!("Invalid number of iterations '{}'", iterations)),
+    }
+}
+
+fn parse_run_args(args: &[String]) -> Result<Args, String> {
+    let mut args = args.iter().peekable();
+
+    let mut day = None;
+    let mut input = None;
+    let mut check = false;
+    let mut answers = None;
+    let mut bench = None;
+    let mut options = Options::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                let file = args.next().ok_or("--input needs a path")?;
+                input = Some(file.clone());
+            }
+            "--check" => check = true,
+            "--answers" => {
+                let file = args.next().ok_or("--answers needs a path")?;
+                answers = Some(file.clone());
+            }
+            "--bench" => bench = Some(parse_iterations(args.next())?),
+            // Anything else is for the day itself, with a value unless another option follows
+            _ if arg.starts_with("--") && arg.len() > 2 => {
+                let value = args.next_if(|next| !next.starts_with("--"));
+                options.add(&arg[2..], value.map(|value| value.as_str()));
+            }
+            _ if day.is_none() => {
+                let number: u8 = arg.parse().map_err(|_| format!("Invalid day '{}'", arg))?;
+                if !DAYS.contains(&number) {
+                    return Err(format!("Day {} is not solved (yet?)", number));
+                }
+                day = Some(number);
+            }
+            _ => return Err(format!("Unexpected argument '{}'", arg)),
+        }
+    }
+
+    if answers.is_some() && !check {
+        return Err("--answers is only used with --check".to_string());
+    }
+    // There's no answers.toml next to stdin...
+    if check && answers.is_none() && input.as_deref() == Some(STDIN) {
+        return Err("--check with input from stdin needs --answers".to_string());
+    }
+
+    let days = match day {
+        Some(day) => vec![day],
+        // One input (or answers) file can't be the one for all the days...
+        None if input.is_some() => return Err("--input needs a specific day".to_string()),
+        None if answers.is_some() => return Err("--answers needs a specific day".to_string()),
+        None if !options.is_empty() => return Err("Options for a day need a specific day".to_string()),
+        None => DAYS.to_vec(),
+    };
+
+    Ok(Args { days, input, check, answers, bench, options })
+}
+
+fn parse_readme_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+
+    let mut iterations = README_ITERATIONS;
+    let mut readme = "README.md".to_string();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bench" => iterations = parse_iterations(args.next())?,
+            "--readme" => {
+                let file = args.next().ok_or("--readme needs a path")?;
+                readme = file.clone();
+            }
+            _ => return Err(format!("Unexpected argument '{}'", arg)),
+        }
+    }
+
+    Ok(Command::Readme { iterations, readme })
+}
+
+// Run one of the parts, measuring its execution time
+fn time_part<T: Display>(part: u8, solve: impl FnOnce() -> T) -> String {
+    let start = Instant::now();
+    let result = solve();
+    let elapsed = start.elapsed();
+    if part > 1 {
+        println!();
+    }
+    println!("Part {}: {:#}\n        {:?}", part, result, elapsed);
+    result.to_string()
+}
+
+// Every day is run the same way, thanks to the Day trait
+fn run<D: Day>(job: &Job) -> Result<Outcome, Box<dyn Error>> {
+    if let Some(iterations) = job.bench {
+        return bench_run::<D>(job, iterations);
+    }
+
+    let mut solver = D::read_input(&job.filename)?;
+    configure(&mut solver, &job.options)?;
+    let mut correct = true;
+
+    let answer = time_part(1, || solver.part1());
+    if let Some(answers) = &job.answers {
+        correct &= answers::check(&answer, answers.part1.as_deref());
+    }
+
+    let answer = time_part(2, || solver.part2());
+    if let Some(answers) = &job.answers {
+        correct &= answers::check(&answer, answers.part2.as_deref());
+    }
+
+    Ok(Outcome { correct, timings: None })
+}
+
+// Give the day its options, and anything it didn't take is a mistake on the command line
+fn configure<D: Day>(solver: &mut D, options: &Options) -> Result<(), Box<dyn Error>> {
+    let mut options = options.clone();
+    solver.configure(&mut options)?;
+    let unknown: Vec<String> = options.remaining().map(|name| format!("--{}", name)).collect();
+    if !unknown.is_empty() {
+        return Err(format!("Unknown option {} for day {:02}", unknown.join(", "), D::DAY).into());
+    }
+    Ok(())
+}
+
+// Like run, but parsing and each of the parts are measured over many iterations
+fn bench_run<D: Day>(job: &Job, iterations: usize) -> Result<Outcome, Box<dyn Error>> {
+    // Reading the file is not part of what we measure, only the parsing
+    let input = aoc_common::read_source(&job.filename)?;
+    let mut correct = true;
+
+    // Let's just make sure we can parse before measuring anything
+    let mut solver = D::parse(&input)?;
+    configure(&mut solver, &job.options)?;
+    let (parse, _) = timing::measure(iterations, || D::parse(&input));
+    println!("Parse:  {}", parse);
+
+    let (part1, answer) = timing::measure(iterations, || solver.part1());
+    println!("\nPart 1: {:#}\n        {}", answer, part1);
+    if let Some(answers) = &job.answers {
+        correct &= answers::check(&answer.to_string(), answers.part1.as_deref());
+    }
+
+    let (part2, answer) = timing::measure(iterations, || solver.part2());
+    println!("\nPart 2: {:#}\n        {}", answer, part2);
+    if let Some(answers) = &job.answers {
+        correct &= answers::check(&answer.to_string(), answers.part2.as_deref());
+    }
+
+    Ok(Outcome {
+        correct,
+        timings: Some(Timings { parse, part1, part2 }),
+    })
+}
+
+fn run_day(day: u8, job: &Job) -> Result<Outcome, Box<dyn Error>> {
+    match day {
+        1 => run::<day01::Solver>(job),
+        2 => run::<day02::Solver>(job),
+        3 => run::<day03::Solver>(job),
+        4 => run::<day04::Solver>(job),
+        5 => run::<day05::Solver>(job),
+        6 => run::<day06::Solver>(job),
+        7 => run::<day07::Solver>(job),
+        8 => run::<day08::Solver>(job),
+        9 => run::<day09::Solver>(job),
+        10 => run::<day10::Solver>(job),
+        11 => run::<day11::Solver>(job),
+        12 => run::<day12::Solver>(job),
+        _ => unreachable!("day {} is validated when parsing the arguments", day),
+    }
+}
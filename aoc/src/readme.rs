@@ -0,0 +1,74 @@
+use crate::Timings;
+use std::error::Error;
+use std::fs;
+use std::time::Duration;
+
+// Everything between these two lines in the README is generated, and will be replaced
+const START_MARKER: &str = "<!-- results:start -->";
+const END_MARKER: &str = "<!-- results:end -->";
+
+pub fn update(file: &str, iterations: usize, results: &[(u8, Timings)]) -> Result<(), Box<dyn Error>> {
+    let content = fs::read_to_string(file)?;
+    let updated = replace_section(&content, &render_table(iterations, results))?;
+    fs::write(file, updated)?;
+    Ok(())
+}
+
+// A markdown table with the median times of each day, and a grand total
+fn render_table(iterations: usize, results: &[(u8, Timings)]) -> String {
+    let mut table = format!(
+        "Median times over {} runs, generated by `cargo run --release -p aoc -- readme`\n\n",
+        iterations
+    );
+    table.push_str("| Day | Parse | Part 1 | Part 2 | Total |\n");
+    table.push_str("|----:|------:|-------:|-------:|------:|\n");
+
+    let mut total = Duration::ZERO;
+    for (day, timings) in results {
+        let day_total = timings.parse.median + timings.part1.median + timings.part2.median;
+        total += day_total;
+
+        table.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            day,
+            format_duration(timings.parse.median),
+            format_duration(timings.part1.median),
+            format_duration(timings.part2.median),
+            format_duration(day_total),
+        ));
+    }
+    table.push_str(&format!("| **All** | | | | **{}** |\n", format_duration(total)));
+
+    table
+}
+
+// Duration's own Debug formatting, but with a bit less noisy decimals
+fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_micros(1) {
+        format!("{}ns", duration.as_nanos())
+    } else {
+        format!("{:.2?}", duration)
+    }
+}
+
+fn replace_section(content: &str, section: &str) -> Result<String, String> {
+    let start = content
+        .find(START_MARKER)
+        .ok_or_else(|| format!("no '{}' line to put the results after", START_MARKER))?;
+    let end = content
+        .find(END_MARKER)
+        .ok_or_else(|| format!("no '{}' line to put the results before", END_MARKER))?;
+    if end < start {
+        return Err(format!("'{}' comes before '{}'", END_MARKER, START_MARKER));
+    }
+
+    Ok(format!(
+        "{}\n{}{}",
+        &content[..start + START_MARKER.len()],
+        section,
+        &content[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests;
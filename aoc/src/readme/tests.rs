@@ -0,0 +1,47 @@
+use super::*;
+use aoc_common::timing::Stats;
+
+fn timings(parse: u64, part1: u64, part2: u64) -> Timings {
+    let stats = |micros| Stats::from_samples(&[Duration::from_micros(micros)]);
+    Timings {
+        parse: stats(parse),
+        part1: stats(part1),
+        part2: stats(part2),
+    }
+}
+
+#[test]
+fn replace_keeps_everything_around_the_section() {
+    let readme = "# Title\n<!-- results:start -->\nold stuff\n<!-- results:end -->\n## Day 1\n";
+    let updated = replace_section(readme, "new stuff\n").unwrap();
+    assert_eq!(
+        updated,
+        "# Title\n<!-- results:start -->\nnew stuff\n<!-- results:end -->\n## Day 1\n"
+    );
+
+    // And again, to see that it's stable
+    assert_eq!(replace_section(&updated, "new stuff\n").unwrap(), updated);
+}
+
+#[test]
+fn replace_needs_both_markers_in_order() {
+    assert!(replace_section("no markers at all", "").is_err());
+    assert!(replace_section("<!-- results:start -->", "").is_err());
+    assert!(replace_section("<!-- results:end -->\n<!-- results:start -->", "").is_err());
+}
+
+#[test]
+fn table_has_a_row_per_day_and_a_total() {
+    let table = render_table(10, &[(1, timings(1, 2, 3)), (2, timings(1000, 2000, 3000))]);
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines[0], "Median times over 10 runs, generated by `cargo run --release -p aoc -- readme`");
+    assert_eq!(lines[4], "| 1 | 1.00µs | 2.00µs | 3.00µs | 6.00µs |");
+    assert_eq!(lines[5], "| 2 | 1.00ms | 2.00ms | 3.00ms | 6.00ms |");
+    assert_eq!(lines[6], "| **All** | | | | **6.01ms** |");
+}
+
+#[test]
+fn short_durations_have_no_decimals() {
+    assert_eq!(format_duration(Duration::from_nanos(82)), "82ns");
+    assert_eq!(format_duration(Duration::from_nanos(1500)), "1.50µs");
+}
@@ -0,0 +1,196 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+// Everything is relative to the workspace root, like the default inputs
+const TEMPLATE: &str = "template_day";
+const WORKSPACE_MANIFEST: &str = "Cargo.toml";
+const RUNNER_MANIFEST: &str = "aoc/Cargo.toml";
+const RUNNER_MAIN: &str = "aoc/src/main.rs";
+
+// The template's own test is for its test.txt, a new day starts out with an empty one
+const TESTS: &str = r#"use super::*;
+
+// The example from the puzzle description, paste it into test.txt
+const EXAMPLE: &str = include_str!("../test.txt");
+
+// Put in the example answers from the puzzle description here
+#[test]
+fn example_part1() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part1(), 0);
+}
+
+#[test]
+fn example_part2() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part2(), 0);
+}
+"#;
+
+// With the day number filled in
+const ANSWERS: &str = "# The answers for input.txt, checked with: aoc run {day} --check
+# part1 =
+# part2 =
+";
+
+// Create a new day from the template, and register it everywhere it needs to be
+pub fn new_day(name: &str) -> Result<(), Box<dyn Error>> {
+    let day = day_number(name)?;
+    if Path::new(name).exists() {
+        return Err(format!("'{}' already exists", name).into());
+    }
+
+    // Let's do all the editing before writing anything, so that a failure
+    // doesn't leave us with a half registered day
+    let workspace = add_workspace_member(&fs::read_to_string(WORKSPACE_MANIFEST)?, day)?;
+    let runner_manifest = add_runner_dependency(&fs::read_to_string(RUNNER_MANIFEST)?, day)?;
+    let runner_main = add_runner_day(&fs::read_to_string(RUNNER_MAIN)?, day)?;
+    let manifest = fs::read_to_string(format!("{}/Cargo.toml", TEMPLATE))?
+        .replace(&format!("name = \"{}\"", TEMPLATE), &format!("name = \"{}\"", name));
+
+    // Copy the template sources, with the day number filled in and tests for the example
+    fs::create_dir_all(format!("{}/src", name))?;
+    for entry in fs::read_dir(format!("{}/src", TEMPLATE))? {
+        let path = entry?.path();
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy().into_owned();
+        let content = match file_name.as_str() {
+            "lib.rs" => set_day_number(&fs::read_to_string(&path)?, day)?,
+            "tests.rs" => TESTS.to_string(),
+            _ => fs::read_to_string(&path)?,
+        };
+        fs::write(format!("{}/src/{}", name, file_name), content)?;
+    }
+    fs::write(format!("{}/Cargo.toml", name), manifest)?;
+    fs::write(format!("{}/input.txt", name), "")?;
+    fs::write(format!("{}/test.txt", name), "")?;
+    fs::write(format!("{}/answers.toml", name), ANSWERS.replace("{day}", &day.to_string()))?;
+
+    fs::write(WORKSPACE_MANIFEST, workspace)?;
+    fs::write(RUNNER_MANIFEST, runner_manifest)?;
+    fs::write(RUNNER_MAIN, runner_main)?;
+
+    Ok(())
+}
+
+// Day names are always two digits, so they sort nicely: day01, not day1
+fn day_number(name: &str) -> Result<u8, String> {
+    let invalid = || format!("Invalid day name '{}', expected dayNN, like day01", name);
+    let digits = name.strip_prefix("day").ok_or_else(invalid)?;
+    if digits.len() != 2 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    match digits.parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("There's no {} in an advent calendar", name)),
+    }
+}
+
+// The day crates are named the same everywhere
+fn crate_name(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+fn set_day_number(lib: &str, day: u8) -> Result<String, String> {
+    replace_line(lib, |line| line.trim_start().starts_with("const DAY: u8 ="), |line| {
+        let indent = &line[..line.len() - line.trim_start().len()];
+        format!("{}const DAY: u8 = {};", indent, day)
+    })
+    .ok_or_else(|| format!("No 'const DAY: u8 = ...' in the {} lib.rs", TEMPLATE))
+}
+
+fn add_workspace_member(manifest: &str, day: u8) -> Result<String, String> {
+    // "day07",
+    let day_of = |line: &str| {
+        let name = line.trim().strip_prefix('"')?.strip_suffix("\",")?;
+        day_of_crate(name)
+    };
+    insert_day_line(manifest, day, &format!("    \"{}\",", crate_name(day)), day_of)
+        .map_err(|e| format!("{} in the workspace members in {}", e, WORKSPACE_MANIFEST))
+}
+
+fn add_runner_dependency(manifest: &str, day: u8) -> Result<String, String> {
+    // day07 = { path = "../day07" }
+    let day_of = |line: &str| day_of_crate(line.split_once(" = ")?.0);
+    let name = crate_name(day);
+    insert_day_line(manifest, day, &format!("{} = {{ path = \"../{}\" }}", name, name), day_of)
+        .map_err(|e| format!("{} in the dependencies in {}", e, RUNNER_MANIFEST))
+}
+
+fn add_runner_day(main: &str, day: u8) -> Result<String, String> {
+    // 7 => run::<day07::Solver>(job),
+    let day_of = |line: &str| line.trim().split_once(" => run::<")?.0.parse().ok();
+    let dispatch = format!("        {} => run::<{}::Solver>(job),", day, crate_name(day));
+    let main = insert_day_line(main, day, &dispatch, day_of)
+        .map_err(|e| format!("{} in the day dispatch in {}", e, RUNNER_MAIN))?;
+
+    // And the list of days, that the dispatch is checked against
+    let main = replace_line(&main, |line| line.starts_with("const DAYS: &[u8] = &["), |line| {
+        let mut days: Vec<u8> = line
+            .trim_start_matches("const DAYS: &[u8] = &[")
+            .trim_end_matches("];")
+            .split(',')
+            .filter_map(|s| s.trim().parse().ok())
+            .collect();
+        days.push(day);
+        days.sort();
+        let days: Vec<String> = days.iter().map(|day| day.to_string()).collect();
+        format!("const DAYS: &[u8] = &[{}];", days.join(", "))
+    });
+    main.ok_or_else(|| format!("No 'const DAYS: &[u8] = &[...];' in {}", RUNNER_MAIN))
+}
+
+fn day_of_crate(name: &str) -> Option<u8> {
+    let digits = name.strip_prefix("day")?;
+    if digits.len() == 2 { digits.parse().ok() } else { None }
+}
+
+// Insert a line among the ones for the other days, keeping them in order of the days
+fn insert_day_line(
+    content: &str,
+    day: u8,
+    new_line: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Result<String, String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect();
+
+    if days.iter().any(|&(_, other)| other == day) {
+        return Err(format!("Day {} is already there", day));
+    }
+    let position = match days.iter().find(|&&(_, other)| other > day) {
+        Some(&(i, _)) => i,
+        None => days.last().ok_or("Couldn't find where to add the day")?.0 + 1,
+    };
+
+    lines.insert(position, new_line);
+    Ok(join_lines(content, &lines))
+}
+
+// Replace the first line matching, None if there isn't one
+fn replace_line(
+    content: &str,
+    matches: impl Fn(&str) -> bool,
+    replace: impl FnOnce(&str) -> String,
+) -> Option<String> {
+    let mut lines: Vec<String> = content.lines().map(String::from).collect();
+    let line = lines.iter_mut().find(|line| matches(line))?;
+    *line = replace(line);
+    Some(join_lines(content, &lines))
+}
+
+// Back to one string, keeping the final newline if there was one
+fn join_lines<S: AsRef<str>>(original: &str, lines: &[S]) -> String {
+    let mut joined = lines.iter().map(|line| line.as_ref()).collect::<Vec<&str>>().join("\n");
+    if original.ends_with('\n') {
+        joined.push('\n');
+    }
+    joined
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,54 @@
+use super::*;
+
+#[test]
+fn day_names_are_two_digits() {
+    assert_eq!(day_number("day01"), Ok(1));
+    assert_eq!(day_number("day25"), Ok(25));
+    assert!(day_number("day1").is_err());
+    assert!(day_number("day001").is_err());
+    assert!(day_number("Day01").is_err());
+    assert!(day_number("day+1").is_err());
+    assert!(day_number("day00").is_err());
+    assert!(day_number("day26").is_err());
+}
+
+#[test]
+fn workspace_members_stay_in_order() {
+    let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day09\",\n    \"day11\",\n    \"template_day\",\n]\n";
+    assert_eq!(
+        add_workspace_member(manifest, 10).unwrap(),
+        "[workspace]\nmembers = [\n    \"aoc\",\n    \"day09\",\n    \"day10\",\n    \"day11\",\n    \"template_day\",\n]\n"
+    );
+    assert_eq!(
+        add_workspace_member(manifest, 12).unwrap(),
+        "[workspace]\nmembers = [\n    \"aoc\",\n    \"day09\",\n    \"day11\",\n    \"day12\",\n    \"template_day\",\n]\n"
+    );
+    assert!(add_workspace_member(manifest, 11).is_err());
+}
+
+#[test]
+fn runner_dependency() {
+    let manifest = "[dependencies]\naoc-common = { path = \"../aoc-common\" }\nday01 = { path = \"../day01\" }\n\n[lints]\n";
+    assert_eq!(
+        add_runner_dependency(manifest, 2).unwrap(),
+        "[dependencies]\naoc-common = { path = \"../aoc-common\" }\nday01 = { path = \"../day01\" }\nday02 = { path = \"../day02\" }\n\n[lints]\n"
+    );
+}
+
+#[test]
+fn runner_dispatch_and_days() {
+    let main = "const DAYS: &[u8] = &[1, 2];\n\nfn run_day() {\n    match day {\n        1 => run::<day01::Solver>(job),\n        2 => run::<day02::Solver>(job),\n        _ => unreachable!(),\n    }\n}\n";
+    assert_eq!(
+        add_runner_day(main, 13).unwrap(),
+        "const DAYS: &[u8] = &[1, 2, 13];\n\nfn run_day() {\n    match day {\n        1 => run::<day01::Solver>(job),\n        2 => run::<day02::Solver>(job),\n        13 => run::<day13::Solver>(job),\n        _ => unreachable!(),\n    }\n}\n"
+    );
+    assert!(add_runner_day(main, 2).is_err());
+    assert!(add_runner_day("fn main() {}\n", 3).is_err());
+}
+
+#[test]
+fn day_number_in_the_template() {
+    let lib = "impl Day for Solver {\n    const DAY: u8 = 0; // set by aoc new\n}\n";
+    assert_eq!(set_day_number(lib, 7).unwrap(), "impl Day for Solver {\n    const DAY: u8 = 7;\n}\n");
+    assert!(set_day_number("no day here", 7).is_err());
+}
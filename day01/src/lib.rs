@@ -1,46 +1,42 @@
-use std::env;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::time::Instant;
+use aoc_common::{Day, ParseError, lines};
 
-fn main() {
-    // Get the input filename from command line arguments or default to "input.txt"
-    let args: Vec<String> = env::args().collect();
-    let filename = args.get(1).map(|s| s.as_str()).unwrap_or("input.txt");
+pub struct Solver {
+    rotations: Vec<(char, i32)>,
+}
 
-    // Read the input file
-    match read_input(filename) {
-        // If successful, run parts 1 and 2 and measure their execution time
-        Ok(contents) => {
-            // Part 1
-            let start = Instant::now();
-            let result1 = part1(&contents);
-            println!("Part 1: {}\n        {:?}", result1, start.elapsed());
+impl Day for Solver {
+    const DAY: u8 = 1;
 
-            // Part 2
-            let start = Instant::now();
-            let result2 = part2(&contents);
-            println!("\nPart 2: {}\n        {:?}", result2, start.elapsed());
-        }
+    type Answer1 = i32;
+    type Answer2 = i32;
 
-        // If there was an error reading the file, print an error message
-        Err(e) => {
-            eprintln!("Error reading input {}: {}", filename, e);
-        }
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver { rotations: parse_input(input)? })
     }
-}
 
-fn read_input(file: &str) -> Result<Vec<(char, i32)>, Box<dyn std::error::Error>> {
-    let input_file = File::open(file)?;
-    let buffered = BufReader::new(input_file);
+    fn part1(&self) -> i32 {
+        part1(&self.rotations)
+    }
 
+    fn part2(&self) -> i32 {
+        part2(&self.rotations)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(char, i32)>, ParseError> {
     let mut data: Vec<(char, i32)> = Vec::new();
-    // Read each line from the file and parse it accordingly
-    for line in buffered.lines() {
-        let line = line?; // I need to bind this here so it lives longer...?
-        let mut chars = line.chars();
-        let direction = chars.next().ok_or("Crazy input data")?;
-        let distance: i32 = chars.as_str().trim().parse()?;
+    // Parse each line of the input accordingly
+    for line in lines(Solver::DAY, input) {
+        let mut chars = line.text.chars();
+        let direction = match chars.next() {
+            Some(direction @ ('L' | 'R')) => direction,
+            _ => return Err(line.error(line.text, "crazy input data, expected a rotation like L68 or R14")),
+        };
+        let distance_str = chars.as_str().trim();
+        let distance: i32 = line.parse(distance_str, "distance")?;
+        if distance < 0 {
+            return Err(line.error(distance_str, "the dial can't rotate a negative distance"));
+        }
         data.push((direction, distance));
     }
 
@@ -1,5 +1,8 @@
 use super::*;
 
+// The example from the puzzle description
+const EXAMPLE: &str = include_str!("../test.txt");
+
 #[test]
 fn test_1() {
     // 50 -> 99 -> 01 without crossing zero
@@ -62,3 +65,22 @@ fn test_9() {
     let count = part2(&[('R', 1000)]);
     assert_eq!(count, 10);
 }
+
+#[test]
+fn test_inline_input() {
+    // Same as test_5, but all the way from the input text
+    let solver = Solver::parse("L50\nL100\n").unwrap();
+    assert_eq!(solver.part2(), 2);
+}
+
+#[test]
+fn example_part1() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part1(), 3);
+}
+
+#[test]
+fn example_part2() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part2(), 6);
+}
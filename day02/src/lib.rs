@@ -1,50 +1,48 @@
+use aoc_common::{Day, ParseError, lines};
 use std::collections::HashSet;
-use std::env;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::time::Instant;
-
-fn main() {
-    // Get the input filename from command line arguments or default to "input.txt"
-    let args: Vec<String> = env::args().collect();
-    let filename = args.get(1).map(|s| s.as_str()).unwrap_or("input.txt");
-
-    // Read the input file
-    match read_input(filename) {
-        // If successful, run parts 1 and 2 and measure their execution time
-        Ok(contents) => {
-            // Part 1
-            let start = Instant::now();
-            let result1 = part1(&contents);
-            println!("Part 1: {}\n        {:?}", result1, start.elapsed());
-
-            // Part 2
-            let start = Instant::now();
-            let result2 = part2(&contents);
-            println!("\nPart 2: {}\n        {:?}", result2, start.elapsed());
-        }
 
-        // If there was an error reading the file, print an error message
-        Err(e) => {
-            eprintln!("Error reading input {}: {}", filename, e);
-        }
-    }
+pub struct Solver {
+    ranges: Vec<(u64, u64)>,
 }
 
-fn read_input(file: &str) -> Result<Vec<(u64, u64)>, Box<dyn std::error::Error>> {
-    let input_file = File::open(file)?;
-    let buffered = BufReader::new(input_file);
+impl Day for Solver {
+    const DAY: u8 = 2;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
 
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver { ranges: parse_input(input)? })
+    }
+
+    fn part1(&self) -> u64 {
+        part1(&self.ranges)
+    }
+
+    fn part2(&self) -> u64 {
+        part2(&self.ranges)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
     let mut data: Vec<(u64, u64)> = Vec::new();
 
-    // Read each line from the file and parse it accordingly
-    for line in buffered.lines() {
-        let line = line?;
-        let ranges: Vec<&str> = line.trim().split(',').collect();
+    // Parse each line of the input accordingly
+    for line in lines(Solver::DAY, input) {
+        let ranges: Vec<&str> = line.text.trim().split(',').collect();
         for range in ranges {
-            let (start_str, end_str) = range.split_once('-').ok_or("Invalid range format")?;
-            let start: u64 = start_str.parse()?;
-            let end: u64 = end_str.parse()?;
+            // A trailing comma is fine, nothing more to see there
+            if range.is_empty() {
+                continue;
+            }
+            let (start_str, end_str) = range
+                .split_once('-')
+                .ok_or_else(|| line.error(range, "invalid range format, expected start-end"))?;
+            let start: u64 = line.parse(start_str, "range start")?;
+            let end: u64 = line.parse(end_str, "range end")?;
+            if start > end {
+                return Err(line.error(range, "the range ends before it starts"));
+            }
             data.push((start, end));
         }
     }
@@ -1,7 +1,22 @@
 use super::*;
 
+// The example from the puzzle description
+const EXAMPLE: &str = include_str!("../test.txt");
+
 #[test]
 fn dummy_test() {
     let sum = part2(&[(2,17)]);
     assert_eq!(sum, 11);
-}
\ No newline at end of file
+}
+
+#[test]
+fn example_part1() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part1(), 1227775554);
+}
+
+#[test]
+fn example_part2() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part2(), 4174379265);
+}
@@ -1,44 +1,44 @@
-use std::env;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::time::Instant;
-
-fn main() {
-    // Get the input filename from command line arguments or default to "input.txt"
-    let args: Vec<String> = env::args().collect();
-    let filename = args.get(1).map(|s| s.as_str()).unwrap_or("input.txt");
-
-    // Read the input file
-    match read_input(filename) {
-        // If successful, run parts 1 and 2 and measure their execution time
-        Ok(contents) => {
-            // Part 1
-            let start = Instant::now();
-            let result1 = part1(&contents);
-            println!("Part 1: {}\n        {:?}", result1, start.elapsed());
-
-            // Part 2
-            let start = Instant::now();
-            let result2 = part2(&contents);
-            println!("\nPart 2: {}\n        {:?}", result2, start.elapsed());
-        }
+use aoc_common::{Day, ParseError, lines};
 
-        // If there was an error reading the file, print an error message
-        Err(e) => {
-            eprintln!("Error reading input {}: {}", filename, e);
-        }
+pub struct Solver {
+    banks: Vec<String>,
+}
+
+impl Day for Solver {
+    const DAY: u8 = 3;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver { banks: parse_input(input)? })
+    }
+
+    fn part1(&self) -> u64 {
+        part1(&self.banks)
+    }
+
+    fn part2(&self) -> u64 {
+        part2(&self.banks)
     }
 }
 
-fn read_input(file: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
-    let input_file = File::open(file)?;
-    let buffered = BufReader::new(input_file);
+// Part 2 picks this many batteries from each bank, so there'd better be enough of them
+const BATTERIES: usize = 12;
 
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
     let mut data: Vec<String> = Vec::new();
 
-    // Read each line from the file and parse it accordingly
-    for line in buffered.lines() {
-        data.push(line?);
+    // Parse each line of the input accordingly
+    for line in lines(Solver::DAY, input) {
+        // Both parts are working directly on the digits, so let's make sure they are the digits of a rating
+        if let Some((i, c)) = line.text.char_indices().find(|(_, c)| !('1'..='9').contains(c)) {
+            return Err(line.error(&line.text[i..i + c.len_utf8()], "batteries are rated 1-9"));
+        }
+        if line.text.len() < BATTERIES {
+            return Err(line.error(line.text, format!("a bank needs at least {} batteries", BATTERIES)));
+        }
+        data.push(line.text.to_string());
     }
 
     Ok(data)
@@ -83,7 +83,7 @@ fn part2(data: &[String]) -> u64 {
         let mut start_index = 0;
         
         // starting from the left, we need to find 12 maximum digits...
-        for digit_number in (0..12).rev() {
+        for digit_number in (0..BATTERIES).rev() {
             let mut max_digit = 0u8;
             let mut max_index = 0;
         
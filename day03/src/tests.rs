@@ -1,6 +1,22 @@
-// use super::*;
+use super::*;
 
-// #[test]
-// fn dummy_test() {
-//     assert_eq!(1 + 1, 2);
-// }
\ No newline at end of file
+// The example from the puzzle description
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn example_part1() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part1(), 357);
+}
+
+#[test]
+fn example_part2() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part2(), 3121910778619);
+}
+
+#[test]
+fn batteries_are_rated_1_to_9() {
+    let error = Solver::parse("987654321111111\n811111111011119\n").err().unwrap();
+    assert_eq!(error.to_string(), "day 03, line 2, column 10, at '0': batteries are rated 1-9");
+}
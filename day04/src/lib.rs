@@ -0,0 +1,101 @@
+use aoc_common::grid::Grid;
+use aoc_common::{Day, ParseError};
+
+pub struct Solver {
+    grid: Grid<u8>,
+}
+
+impl Day for Solver {
+    const DAY: u8 = 4;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver { grid: parse_input(input)? })
+    }
+
+    fn part1(&self) -> u64 {
+        part1(&self.grid)
+    }
+
+    fn part2(&self) -> u64 {
+        part2(&self.grid)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    // Only empty spots and rolls of paper in the grid
+    Grid::parse(Solver::DAY, input, |c| matches!(c, '.' | '@').then_some(c as u8), "'.' or '@'")
+}
+
+fn part1(data: &Grid<u8>) -> u64 {
+    let mut sum: u64 = 0;
+
+    for (x, y) in data.positions() {
+        if data[(x, y)] == b'@' {
+            // No need to count beyond 4
+            let adjacent = data
+                .neighbours8(x, y)
+                .filter(|&(nx, ny)| data[(nx, ny)] == b'@')
+                .take(4)
+                .count();
+            if adjacent < 4 {
+                sum += 1;
+            }
+        }
+    }
+
+    sum
+}
+
+fn part2(data: &Grid<u8>) -> u64 {
+    let mut sum: u64 = 0;
+
+    // We will forklift away rolls in part 2, so we need a mutable copy of the data
+    // to be able to roll the rolls away...
+    let mut grid = data.clone();
+
+    // We need to reinvestigate rolls after others has been removed, but we don't want
+    // to look through the entire grid all of the time. Let's use like a queue of
+    // coordinates to process.
+    // Initially, that queue would be all of the '@' rolls...
+    let mut roll_queue: Vec<(usize, usize)> = grid.positions().filter(|&pos| grid[pos] == b'@').collect();
+
+    // Let the forklifts do their thing, checking the locations in the queue one by one...
+    let mut queue_index = 0;
+    while queue_index < roll_queue.len() {
+        let (x, y) = roll_queue[queue_index];
+        queue_index += 1;
+
+        // Could have been forklifted away already in a previous run...
+        if grid[(x, y)] != b'@' {
+            continue;
+        }
+
+        // Count neighbors to see if this one can be accessed by a forklift
+        let adjacent = grid
+            .neighbours8(x, y)
+            .filter(|&pos| grid[pos] == b'@')
+            .take(4)    // Leave early
+            .count();
+
+        // If fewer than 4 neighbors, the elves can do their forklift thing
+        if adjacent < 4 {
+            grid[(x, y)] = b'.';
+            sum += 1;
+
+            // Neighboring rolls of this one might now be forkliftable. Add to queue!
+            for pos in grid.neighbours8(x, y) {
+                if grid[pos] == b'@' {
+                    roll_queue.push(pos);
+                }
+            }
+        }
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests;
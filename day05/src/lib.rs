@@ -0,0 +1,112 @@
+use aoc_common::{Day, ParseError, intervals, lines};
+use std::ops::Range;
+
+pub struct Solver {
+    ranges: Vec<Range<u64>>,   // fresh ingredient ID ranges, sorted and merged
+    ids: Vec<u64>,             // available ingredient IDs, sorted
+}
+
+impl Day for Solver {
+    const DAY: u8 = 5;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self) -> u64 {
+        part1(&self.ranges, &self.ids)
+    }
+
+    fn part2(&self) -> u64 {
+        part2(&self.ranges, &self.ids)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Solver, ParseError> {
+    let mut ranges: Vec<Range<u64>> = Vec::new();
+    let mut ids: Vec<u64> = Vec::new();
+
+    // Parse each line of the input accordingly
+    // Get a mutable iterator over the lines so we can read in two steps...
+    let mut lines = lines(Solver::DAY, input);
+
+    // Read ranges until a blank line:
+    for line in lines.by_ref() {
+        if line.text.is_empty() {
+            break;
+        }
+
+        let (start_str, end_str) = line
+            .text
+            .split_once('-')
+            .ok_or_else(|| line.error(line.text, "invalid range format, expected start-end"))?;
+        let start: u64 = line.parse(start_str, "range start")?;
+        let end: u64 = line.parse(end_str, "range end")?;
+        if start > end {
+            return Err(line.error(line.text, "the range ends before it starts"));
+        }
+        // The ranges are half-open from here on, so there has to be room for one past the end
+        let end = end.checked_add(1).ok_or_else(|| line.error(end_str, "the range ends too high up"))?;
+        ranges.push(start..end);
+    }
+
+    // Then read IDs:
+    for line in lines {
+        let id: u64 = line.parse(line.text, "ingredient ID")?;
+        ids.push(id);
+    }
+
+    // Ranges might be overlapping, bordering, or contained...
+    // let's sort and merge them:
+    let merged_ranges = intervals::merge(ranges);
+
+    // Sort IDs as well
+    ids.sort();
+
+    Ok(Solver { ranges: merged_ranges, ids })
+}
+
+fn part1(ranges: &[Range<u64>], ids: &[u64]) -> u64 {
+    let mut count_fresh: u64 = 0;
+
+    // Ah, iterators...
+    // Since we have sorted everything and removed any overlap in the ranges, we can basically 
+    // now just iterate over all the ids in a for loop, while bumping up the range iterator
+    // manually, as needed, and count contained ids until we either run out of ranges or ids...
+
+    // ranges iterator handled manually...
+    let mut ranges = ranges.iter();
+    let mut range = match ranges.next() {
+        Some(r) => r,
+        None => return 0,  // No ranges at all - no ids are fresh
+    };
+
+    for id in ids {
+        // Let's make sure we compare against a reasonable range. The range end being before
+        // the id makes no sense, so then we move to the next range until we're cool...
+        while range.end <= *id {
+            match ranges.next() {
+                Some(next_range) => range = next_range,
+                None => return count_fresh,  // No more ranges - our work here is done
+            }
+        }
+
+        // Right, so are we actually in the range?
+        if range.contains(id) {
+            count_fresh += 1;
+        }
+    }
+
+    count_fresh
+}
+
+fn part2(ranges: &[Range<u64>], _ids: &[u64]) -> u64 {
+    // wow, part 2 is just the total size of all merged ranges...
+    ranges.iter().map(|r| r.end - r.start).sum()
+}
+
+#[cfg(test)]
+mod tests;
\ No newline at end of file
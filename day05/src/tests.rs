@@ -1,6 +1,22 @@
-// use super::*;
+use super::*;
 
-// #[test]
-// fn dummy_test() {
-//     assert_eq!(1 + 1, 2);
-// }
\ No newline at end of file
+// The example from the puzzle description
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn example_part1() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part1(), 3);
+}
+
+#[test]
+fn example_part2() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part2(), 14);
+}
+
+#[test]
+fn range_up_to_the_last_id() {
+    let error = Solver::parse("3-5\n10-18446744073709551615\n\n1\n").err().unwrap();
+    assert_eq!(error.to_string(), "day 05, line 2, column 4, at '18446744073709551615': the range ends too high up");
+}
@@ -1,41 +1,61 @@
-use std::env;
-use std::fs;
-use std::time::Instant;
-
-fn main() {
-    // Get the input filename from command line arguments or default to "input.txt"
-    let args: Vec<String> = env::args().collect();
-    let filename = args.get(1).map(|s| s.as_str()).unwrap_or("input.txt");
-
-    // Read the input file
-    match read_input(filename) {
-        // If successful, run parts 1 and 2 and measure their execution time
-        Ok(contents) => {
-            // Part 1
-            let start = Instant::now();
-            let result1 = part1(&contents);
-            println!("Part 1: {}\n        {:?}", result1, start.elapsed());
-
-            // Part 2
-            let start = Instant::now();
-            let result2 = part2(&contents);
-            println!("\nPart 2: {}\n        {:?}", result2, start.elapsed());
-        }
+use aoc_common::grid::Grid;
+use aoc_common::{Day, Line, ParseError, lines};
+use std::str;
 
-        // If there was an error reading the file, print an error message
-        Err(e) => {
-            eprintln!("Error reading input {}: {}", filename, e);
-        }
+pub struct Solver {
+    worksheet: Grid<u8>,
+}
+
+impl Day for Solver {
+    const DAY: u8 = 6;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver { worksheet: parse_input(input)? })
+    }
+
+    fn part1(&self) -> u64 {
+        part1(&self.worksheet)
+    }
+
+    fn part2(&self) -> u64 {
+        part2(&self.worksheet)
     }
 }
 
-fn read_input(file: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
-    // Just reading the lines into a vector of strings
-    let content = fs::read_to_string(file)?;
-    Ok(content.lines().map(String::from).collect())
+fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    // The worksheet is a grid of characters, since part 2 reads it column by column
+    let worksheet = Grid::parse(
+        Solver::DAY,
+        input,
+        |c| matches!(c, '0'..='9' | ' ' | '+' | '*').then_some(c as u8),
+        "digits, spaces, '+' or '*'",
+    )?;
+
+    // Numbers on every line but the last one, which has the operators
+    let lines: Vec<Line> = lines(Solver::DAY, input).collect();
+    let (operators, numbers) = lines.split_last().expect("the grid isn't empty");
+    if numbers.is_empty() {
+        return Err(operators.error(operators.text, "there are no numbers above the operators"));
+    }
+
+    let bad_char = |line: &Line, valid: fn(char) -> bool, message: &str| {
+        match line.text.char_indices().find(|&(_, c)| !valid(c)) {
+            Some((i, c)) => Err(line.error(&line.text[i..i + c.len_utf8()], message)),
+            None => Ok(()),
+        }
+    };
+    for line in numbers {
+        bad_char(line, |c| c == ' ' || c.is_ascii_digit(), "expected digits or spaces")?;
+    }
+    bad_char(operators, |c| c == ' ' || c == '+' || c == '*', "expected '+', '*' or spaces")?;
+
+    Ok(worksheet)
 }
 
-fn part1(data: &[String]) -> u64 {
+fn part1(data: &Grid<u8>) -> u64 {
     let mut sum: u64 = 0;
     
     // We'll collect all related numbers into a vector of their own directly.
@@ -43,8 +63,9 @@ fn part1(data: &[String]) -> u64 {
     let mut numbers: Vec<Vec<u64>> = Vec::new();
     let mut symbols: Vec<char> = Vec::new();
 
-    for line in data {
+    for row in data.rows() {
         // read all of the things on the line, and then determine if numbers or symbols...
+        let line = str::from_utf8(row).expect("the worksheet is all ascii");
         let all_of_the_things: Vec<&str> = line.split_whitespace().collect();
         
         if let Some(first_thing) = all_of_the_things.first() {
@@ -84,21 +105,17 @@ fn part1(data: &[String]) -> u64 {
     sum
 }
 
-fn part2(data: &[String]) -> u64 {
+fn part2(data: &Grid<u8>) -> u64 {
     let mut sum: u64 = 0;
 
     // reading vertically now. with operators being at the very left of each problem,
     // we go from right to left, collecting vertical sums as we go, until we reach the
     // operator. At that point we can just calculate what we have so far...
-    let width = data[0].len();
-    let height = data.len();
-
     let mut col_sums: Vec<u64> = Vec::new();
 
-    for x in (0..width).rev() {
+    for x in (0..data.width()).rev() {
         let mut col_sum: u64 = 0;
-        for y in 0..height {
-            let c = data[y].as_bytes()[x];
+        for &c in data.column(x) {
             match c {
                 b' ' => (),             // space - ignore
                 b'0'..=b'9' => {        // digit - build the vertical sum
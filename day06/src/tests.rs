@@ -1,6 +1,16 @@
-// use super::*;
+use super::*;
 
-// #[test]
-// fn dummy_test() {
-//     assert_eq!(1 + 1, 2);
-// }
\ No newline at end of file
+// The example from the puzzle description
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn example_part1() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part1(), 4277556);
+}
+
+#[test]
+fn example_part2() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part2(), 3263827);
+}
@@ -0,0 +1,112 @@
+use aoc_common::graph::count_paths;
+use aoc_common::grid::Grid;
+use aoc_common::{Day, ParseError};
+
+fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    // The "empty" lines containing only ....... are kept too, they are no trouble for the
+    // beams, and then we don't have to trust that every other line is empty
+    Grid::parse(Solver::DAY, input, |c| matches!(c, '.' | '^' | 'S').then_some(c as u8), "'.', '^' or 'S'")
+}
+
+// For part 2, instead of having global data (for the recursive and memoization stuff)
+// let's use a struct + impl to hold the things...
+// (And I also refactored part 1 into here)
+pub struct Solver {
+    grid: Grid<u8>,
+    start_pos: usize,
+}
+
+impl Day for Solver {
+    const DAY: u8 = 7;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let grid = parse_input(input)?;
+
+        // We start at the 'S' on the first line...
+        let start_pos = grid.row(0).iter().position(|&c| c == b'S').ok_or_else(|| {
+            let first_line = input.lines().next().unwrap_or("");
+            ParseError::new(Self::DAY, 1, 1, first_line, "hey we need an 'S' on the first line to start")
+        })?;
+
+        Ok(Solver { grid, start_pos })
+    }
+
+    fn part1(&self) -> u64 {
+        let mut split_count = 0;
+
+        // Data is relatively narrow, we can just track beams as a vector of booleans,
+        // for better performance than like a HashSet, that I tried first...
+        let width = self.grid.width();
+        let mut beams = vec![false; width];
+        let mut new_beams = vec![false; width];
+            
+        beams[self.start_pos] = true;
+
+        // Loop the lines below the start and count the splits
+        for line in self.grid.rows().skip(1) { 
+            new_beams.fill(false);
+
+            // we _are_ checking all x positions, but with the beams boolean vector
+            // this is still pretty performant...
+            for i in 0..width {
+                if beams[i] {
+                    if line[i] == b'^' {
+                        // Splitter, new beams are to left and right
+                        if i > 0 {
+                            new_beams[i - 1] = true;
+                        }
+                        if i + 1 < width {
+                            new_beams[i + 1] = true;
+                        }
+                        split_count += 1;
+                    } else {
+                        // Empty space, this one continues down
+                        new_beams[i] = true;
+                    }
+                }
+            }
+
+            // swap the old and new beam vectors for next line...
+            std::mem::swap(&mut beams, &mut new_beams);
+        }
+
+        split_count
+    }
+
+    fn part2(&self) -> u64 {
+        // Every timeline is a path down through the splitters, until the beam leaves the bottom.
+        // Lots of them end up in the same places though, so the counting is memoized
+        count_paths(
+            (self.start_pos, 0),
+            |&(_, y)| y >= self.grid.height(),
+            |&(x, y)| self.next_positions(x, y),
+        )
+    }
+
+}
+
+impl Solver {
+    // Where the beam can go from here, in one of the quantum timelines
+    fn next_positions(&self, x: usize, y: usize) -> Vec<(usize, usize)> {
+        match self.grid[(x, y)] {
+            b'^' => {           // Splitter, we go both left and right here...
+                let mut next = Vec::with_capacity(2);
+                if x > 0 {
+                    next.push((x - 1, y + 1));
+                }
+                if x + 1 < self.grid.width() {
+                    next.push((x + 1, y + 1));
+                }
+                next
+            }
+
+            _ => vec![(x, y + 1)]   // Empty space, just continue down
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -1,6 +1,28 @@
-// use super::*;
+use super::*;
 
-// #[test]
-// fn dummy_test() {
-//     assert_eq!(1 + 1, 2);
-// }
\ No newline at end of file
+// The example from the puzzle description
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn missing_start_is_an_error() {
+    let error = Solver::parse("...\n...\n.^.\n").err().unwrap();
+    assert_eq!((error.line, error.column), (1, 1));
+}
+
+#[test]
+fn example_part1() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part1(), 21);
+}
+
+#[test]
+fn example_part2() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part2(), 40);
+}
+
+#[test]
+fn example_without_the_empty_lines() {
+    let solver = Solver::parse(include_str!("../test2.txt")).unwrap();
+    assert_eq!((solver.part1(), solver.part2()), (21, 40));
+}
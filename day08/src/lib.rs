@@ -0,0 +1,189 @@
+use aoc_common::{Day, ParseError, lines};
+
+struct JunctionBox {
+    id: usize,
+    x: u64,
+    y: u64,
+    z: u64,
+}
+
+impl JunctionBox {
+    fn new(id: usize, x: u64, y: u64, z: u64) -> Self {
+        JunctionBox { id, x, y, z }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<JunctionBox>, ParseError> {
+    let mut data: Vec<JunctionBox> = Vec::new();
+    // Parse each line of the input accordingly
+    for (id, line) in lines(Solver::DAY, input).enumerate() {
+        let coordinates: Vec<u64> = line.parse_list(line.text, ',', "coordinate")?;
+        if coordinates.len() != 3 {
+            return Err(line.error(line.text, "expected the X,Y,Z coordinates of a junction box"));
+        }
+        data.push(JunctionBox::new(
+            id,
+            coordinates[0],
+            coordinates[1],
+            coordinates[2],
+        ));
+    }
+
+    Ok(data)
+}
+struct Distance {
+    from: usize,
+    to: usize,
+    dist: u64,
+}
+
+// This is some kind of disjoint-set / union-find structure, keeping track of
+// which circuit each junction box belongs to
+struct Circuits {
+    circuit: Vec<usize>,
+    circuit_size: Vec<usize>,
+}
+
+impl Circuits {
+    fn new(count: usize) -> Self {
+        Circuits {
+            // each box is its own circuit initially
+            circuit: (0..count).collect(),
+            // and obviously each circuit is size 1
+            circuit_size: vec![1; count],
+        }
+    }
+
+    fn get_circuit(&mut self, box_id: usize) -> usize {
+        // pointing to itself means it's the root and hence the circuit id
+        if self.circuit[box_id] == box_id {
+            return box_id;
+        }
+        
+        // otherwise, recurse to find the circuit id (root)
+        let path = self.circuit[box_id];
+
+        // update as we go to be quicker next time (path compression)
+        self.circuit[box_id] = self.get_circuit(path);
+        
+        // now we have it:
+        self.circuit[box_id]
+    }
+
+    fn connect_junction_boxes(&mut self, first: usize, second: usize) {
+        let first_circuit = self.get_circuit(first);
+        let second_circuit = self.get_circuit(second);
+
+        if first_circuit != second_circuit {
+            // We simply point the first circuit to the second one, plus udpate the size, and they are merged
+            self.circuit[first_circuit] = second_circuit;
+            self.circuit_size[second_circuit] += self.circuit_size[first_circuit];
+        }
+    }
+}
+
+pub struct Solver {
+    boxes: Vec<JunctionBox>,
+    distances: Vec<Distance>,
+    initial_connection_count: usize,
+}
+
+impl Day for Solver {
+    const DAY: u8 = 8;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let boxes = parse_input(input)?;
+
+        // The example has 20 junction boxes and connects the 10 closest pairs,
+        // the real input connects 1000 of them
+        let initial_connection_count = match boxes.len() {
+            0..=20 => 10,
+            _ => 1000,
+        };
+
+        Ok(Solver::new(boxes, initial_connection_count))
+    }
+
+    fn part1(&self) -> u64 {
+        let mut circuits = Circuits::new(self.boxes.len());
+
+        // connect the first N boxes with shortest distance
+        for d in self.distances.iter().take(self.initial_connection_count) {
+            circuits.connect_junction_boxes(d.from, d.to);
+        }
+
+        // Collect the sizes of all circuits (with size)
+        let mut sizes: Vec<u64> = circuits
+            .circuit
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &p)| {
+                // it's a "root node" of circuit if it's the same as its pointer
+                // we only count those
+                if i == p {     
+                    Some(circuits.circuit_size[i] as u64)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        // sort descending
+        sizes.sort_by(|a, b| b.cmp(a));
+
+        // product of the top 3
+        sizes.iter().take(3).product()
+    }
+
+    fn part2(&self) -> u64 {
+        let mut circuits = Circuits::new(self.boxes.len());
+
+        // keep connecting boxes, shortest distance first, until all are connected
+        for d in self.distances.iter() {
+            circuits.connect_junction_boxes(d.from, d.to);
+            let circuit = circuits.get_circuit(d.from);
+            // See if this circuit now includes all boxes, if so, we're done
+            if circuits.circuit_size[circuit] == self.boxes.len() {
+                // Answer is the product of the x coordinates of the two boxes that completed the circuit
+                return self.boxes[d.from].x * self.boxes[d.to].x;
+            }
+        }
+
+        0
+    }
+}
+
+impl Solver {
+    fn new(boxes: Vec<JunctionBox>, initial_connection_count: usize) -> Self {
+        // We need all distances between all boxes, let's compute:
+        let mut distances: Vec<Distance> = Vec::new();
+        for i in 0..boxes.len() {
+            for j in (i + 1)..boxes.len() {
+                let euclidian_distance = ((boxes[i].x.abs_diff(boxes[j].x)).pow(2)
+                    + (boxes[i].y.abs_diff(boxes[j].y)).pow(2)
+                    + (boxes[i].z.abs_diff(boxes[j].z)).pow(2)).isqrt();
+                
+                distances.push(Distance {
+                    from: boxes[i].id,
+                    to: boxes[j].id,
+                    dist: euclidian_distance,
+                });
+            }
+        }
+
+        // sort them as we want the shortest distances first
+        distances.sort_by_key(|d| d.dist);
+
+        Solver {
+            boxes,
+            distances,
+            initial_connection_count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -1,6 +1,26 @@
-// use super::*;
+use super::*;
 
-// #[test]
-// fn dummy_test() {
-//     assert_eq!(1 + 1, 2);
-// }
\ No newline at end of file
+// The example from the puzzle description
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn bad_coordinates_are_errors() {
+    // Used to become 0 and 0,0,0 boxes, or just disappear...
+    let error = Solver::parse("1,2,3\n4,x,6\n").err().unwrap();
+    assert_eq!((error.line, error.column, error.text.as_str()), (2, 3, "x"));
+
+    let error = Solver::parse("1,2,3\n4,5\n").err().unwrap();
+    assert_eq!((error.line, error.column), (2, 1));
+}
+
+#[test]
+fn example_part1() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part1(), 40);
+}
+
+#[test]
+fn example_part2() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part2(), 25272);
+}
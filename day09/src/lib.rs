@@ -1,69 +1,32 @@
-use std::env;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::time::Instant;
-
-fn main() {
-    // Get the input filename from command line arguments or default to "input.txt"
-    let args: Vec<String> = env::args().collect();
-    let filename = args.get(1).map(|s| s.as_str()).unwrap_or("input.txt");
-
-    // Read the input file
-    match read_input(filename) {
-        // If successful, run parts 1 and 2 and measure their execution time
-        Ok(contents) => {
-            let solver = Solver::new(&contents);
-
-            // Part 1
-            let start = Instant::now();
-            let result1 = solver.part1();
-            println!("Part 1: {}\n        {:?}", result1, start.elapsed());
-
-            // Part 2
-            let start = Instant::now();
-            let result2 = solver.part2();
-            println!("\nPart 2: {}\n        {:?}", result2, start.elapsed());
-        }
-
-        // If there was an error reading the file, print an error message
-        Err(e) => {
-            eprintln!("Error reading input {}: {}", filename, e);
-        }
-    }
-}
-
-fn read_input(file: &str) -> Result<Vec<(u64, u64)>, Box<dyn std::error::Error>> {
-    // For just reading into a vector of strings, this function could just be:
-    //    let content = fs::read_to_string(file)?;
-    //    Ok(content.lines().map(String::from).collect())
-    // but most day'es well be parsing data here, line by line, so let's have
-    // the buffered reading here...
-    let input_file = File::open(file)?;
-    let buffered = BufReader::new(input_file);
+use aoc_common::{Day, ParseError, lines};
 
+fn parse_input(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
     let mut data: Vec<(u64, u64)> = Vec::new();
 
-    // Read each line from the file and parse it accordingly
-    for line in buffered.lines() {
-        let line = line?;
-        let coordinates: Vec<&str> = line.split(',').collect();
-        if coordinates.len() == 2 {
-            let x = coordinates[0].trim().parse::<u64>()?;
-            let y = coordinates[1].trim().parse::<u64>()?;
-            data.push((x, y));
+    // Parse each line of the input accordingly
+    for line in lines(Solver::DAY, input) {
+        let coordinates: Vec<u64> = line.parse_list(line.text, ',', "coordinate")?;
+        if coordinates.len() != 2 {
+            return Err(line.error(line.text, "expected the X,Y coordinates of a red tile"));
         }
+        data.push((coordinates[0], coordinates[1]));
     }
 
     Ok(data)
 }
 
-struct Solver<'a> {
-    red_tiles: &'a [(u64, u64)],
+pub struct Solver {
+    red_tiles: Vec<(u64, u64)>,
 }
 
-impl<'a> Solver<'a> {
-    fn new(red_tiles: &'a [(u64, u64)]) -> Self {
-        Solver { red_tiles }
+impl Day for Solver {
+    const DAY: u8 = 9;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver { red_tiles: parse_input(input)? })
     }
 
     fn part1(&self) -> u64 {
@@ -0,0 +1,397 @@
+use aoc_common::ilp::{IntegerProgram, Relation};
+use aoc_common::{Day, Line, Options, ParseError, lines};
+use std::fmt;
+
+fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    let mut data: Vec<Machine> = Vec::new();
+
+    // Parse each line of the input accordingly
+    for line in lines(Solver::DAY, input) {
+        let mut machine = Machine {
+            light_diagram: Bits::new(0),
+            button_wiring: Vec::new(),
+            button_bitset: Vec::new(),
+            joltage_requirements: Vec::new(),
+        };
+        let mut light_count = None;
+
+        for part in line.text.split_ascii_whitespace() {
+            match part.chars().next() {
+                Some('[') => {
+                    // strip the brackets and get the light bits
+                    let lights_str = strip(&line, part, '[', ']')?;
+                    if light_count.is_some() {
+                        return Err(line.error(part, "a machine has only one light diagram"));
+                    }
+                    machine.light_diagram = Bits::new(lights_str.len());
+                    for (i, state) in lights_str.char_indices() {
+                        match state {
+                            '#' => machine.light_diagram.toggle(i),
+                            '.' => (),
+                            _ => {
+                                let bad = &lights_str[i..i + state.len_utf8()];
+                                return Err(line.error(bad, "lights are either '.' or '#'"));
+                            }
+                        }
+                    }
+                    light_count = Some(lights_str.len());
+                }
+                Some('(') => {
+                    // strip the parentheses and get the button wirings
+                    let buttons_str = strip(&line, part, '(', ')')?;
+                    let Some(light_count) = light_count else {
+                        return Err(line.error(part, "buttons must come after the light diagram"));
+                    };
+
+                    let mut button_bits = Bits::new(light_count);
+                    let mut button_wiring = Vec::new();
+                    for s in buttons_str.split(',') {
+                        let button: usize = line.parse(s.trim(), "button wiring")?;
+                        if button >= light_count {
+                            return Err(line.error(s, format!("there are only {} lights", light_count)));
+                        }
+                        button_bits.toggle(button);
+                        button_wiring.push(button);
+                    }
+                    machine.button_wiring.push(button_wiring);
+                    machine.button_bitset.push(button_bits);
+                }
+                Some('{') => {
+                    // strip the curly braces and get the joltage requirements
+                    let joltage_str = strip(&line, part, '{', '}')?;
+                    machine.joltage_requirements = line.parse_list(joltage_str, ',', "joltage requirement")?;
+                    // one requirement per counter, and there's a counter for each light
+                    if Some(machine.joltage_requirements.len()) != light_count {
+                        return Err(line.error(part, "expected one joltage requirement per light"));
+                    }
+                }
+                _ => return Err(line.error(part, "expected [lights], (buttons) or {joltages}")),
+            }
+        }
+
+        if light_count.is_none() || machine.joltage_requirements.is_empty() {
+            return Err(line.error(line.text, "a machine needs both a light diagram and joltage requirements"));
+        }
+
+        data.push(machine);
+    }
+
+    Ok(data)
+}
+
+// The inside of a part of the line, like the lights in [.##.]
+fn strip<'a>(line: &Line, part: &'a str, open: char, close: char) -> Result<&'a str, ParseError> {
+    part.strip_prefix(open)
+        .and_then(|s| s.strip_suffix(close))
+        .ok_or_else(|| line.error(part, format!("expected it to be enclosed in {}{}", open, close)))
+}
+
+// A set of bits, like the lights that are on, or the buttons that are pressed. Up to 64
+// of them fit in a single number, any more than that take as many numbers as it needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Bits {
+    Few(u64),
+    Many(Vec<u64>),
+}
+
+impl Bits {
+    // All of the bits off
+    fn new(count: usize) -> Self {
+        if count <= 64 {
+            Bits::Few(0)
+        } else {
+            Bits::Many(vec![0; count.div_ceil(64)])
+        }
+    }
+
+    fn toggle(&mut self, bit: usize) {
+        match self {
+            Bits::Few(bits) => *bits ^= 1 << bit,
+            Bits::Many(words) => words[bit / 64] ^= 1 << (bit % 64),
+        }
+    }
+
+    fn contains(&self, bit: usize) -> bool {
+        match self {
+            Bits::Few(bits) => bits & (1 << bit) != 0,
+            Bits::Many(words) => words[bit / 64] & (1 << (bit % 64)) != 0,
+        }
+    }
+
+    // Toggle all of the bits that are on in the other, like pressing a button toggles
+    // all of the lights it's wired to
+    fn toggle_all(&mut self, other: &Bits) {
+        match (self, other) {
+            (Bits::Few(bits), Bits::Few(other)) => *bits ^= other,
+            (Bits::Many(words), Bits::Many(other)) => words.iter_mut().zip(other).for_each(|(word, other)| *word ^= other),
+            _ => unreachable!("only bits of the same kind of things are combined"),
+        }
+    }
+
+    // The first bit that's on, if any
+    fn first(&self) -> Option<usize> {
+        match self {
+            Bits::Few(bits) => (*bits != 0).then(|| bits.trailing_zeros() as usize),
+            Bits::Many(words) => {
+                let i = words.iter().position(|&word| word != 0)?;
+                Some(i * 64 + words[i].trailing_zeros() as usize)
+            }
+        }
+    }
+
+    fn count(&self) -> u64 {
+        match self {
+            Bits::Few(bits) => bits.count_ones() as u64,
+            Bits::Many(words) => words.iter().map(|word| word.count_ones() as u64).sum(),
+        }
+    }
+}
+
+struct Machine {
+    light_diagram: Bits,
+    button_wiring: Vec<Vec<usize>>,
+    button_bitset: Vec<Bits>,
+    joltage_requirements: Vec<u16>,
+}
+
+// How to get a machine where it needs to be: how many times to press each button,
+// or that it can't be done at all
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Presses {
+    Buttons(Vec<u64>),
+    Infeasible,
+}
+
+impl Presses {
+    // The machines that can't be done don't add any presses
+    pub fn total(&self) -> u64 {
+        match self {
+            Presses::Buttons(presses) => presses.iter().sum(),
+            Presses::Infeasible => 0,
+        }
+    }
+}
+
+// The answer for both parts is the total number of presses for all of the machines, but
+// let's also keep which buttons to press for each of them
+pub struct Pressing {
+    total: u64,
+    presses: Vec<Presses>,       // for each machine
+    explanation: Option<String>, // the buttons to press for each machine, with --explain
+}
+
+impl Pressing {
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    pub fn presses(&self) -> &[Presses] {
+        &self.presses
+    }
+}
+
+impl fmt::Display for Pressing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.total)?;
+        // The machines that can't be done only show in the alternate {:#} form
+        if f.alternate() {
+            let infeasible = self.presses.iter().filter(|&presses| *presses == Presses::Infeasible).count();
+            if infeasible > 0 {
+                write!(f, " ({} of {} machines can't be done)", infeasible, self.presses.len())?;
+            }
+            if let Some(explanation) = &self.explanation {
+                write!(f, "\n{}", explanation.trim_end())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Solver {
+    machines: Vec<Machine>,
+    explain: bool, // show which buttons to press for each machine
+}
+
+impl Day for Solver {
+    const DAY: u8 = 10;
+
+    type Answer1 = Pressing;
+    type Answer2 = Pressing;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver { machines: parse_input(input)?, explain: false })
+    }
+
+    fn part1(&self) -> Pressing {
+        let presses = self.machines.iter().map(|machine| self.press_for_lights(machine)).collect();
+        self.pressing(presses)
+    }
+
+    fn part2(&self) -> Pressing {
+        let presses = self.machines.iter().map(|machine| self.press_for_joltage(machine)).collect();
+        self.pressing(presses)
+    }
+
+    fn configure(&mut self, options: &mut Options) -> Result<(), String> {
+        self.explain = options.flag("explain")?;
+        Ok(())
+    }
+}
+
+impl Solver {
+    fn pressing(&self, presses: Vec<Presses>) -> Pressing {
+        let total = presses.iter().map(Presses::total).sum();
+        let explanation = self.explain.then(|| self.explain(&presses));
+        Pressing { total, presses, explanation }
+    }
+
+    // A line for each machine, with the buttons to press (the way they're wired) and how often
+    fn explain(&self, presses: &[Presses]) -> String {
+        let mut explanation = String::new();
+        for (i, (machine, presses)) in self.machines.iter().zip(presses).enumerate() {
+            explanation += &format!("Machine {}: ", i + 1);
+            match presses {
+                Presses::Infeasible => explanation += "can't be done",
+                Presses::Buttons(buttons) => {
+                    explanation += &format!("{} presses", presses.total());
+                    for (wiring, &count) in machine.button_wiring.iter().zip(buttons) {
+                        if count > 0 {
+                            let wiring: Vec<String> = wiring.iter().map(|light| light.to_string()).collect();
+                            explanation += &format!(", ({}) x{}", wiring.join(","), count);
+                        }
+                    }
+                }
+            }
+            explanation += "\n";
+        }
+        explanation
+    }
+
+    fn press_for_lights(&self, machine: &Machine) -> Presses {
+        // Pressing a button twice is the same as not pressing it at all, and the order doesn't
+        // matter either, so it's all about which buttons are pressed (once). That's linear algebra
+        // with just 0 and 1, where adding is XOR: which buttons add up to the light diagram?
+        let button_count = machine.button_bitset.len();
+
+        // Gaussian elimination, one button at a time: each button is toggled with the earlier
+        // reduced ones until its first light is one that none of them has. Then that light is the
+        // pivot of this one. Along with the lights, we keep track of the buttons that were pressed
+        // to get them. If nothing is left of a button, the buttons that cancelled it out are a way
+        // to press buttons without changing any lights at all, so those are kept as well.
+        let mut reduced: Vec<(usize, Bits, Bits)> = Vec::new(); // (pivot, lights, buttons pressed)
+        let mut no_change: Vec<(usize, Bits)> = Vec::new(); // (the button that was cancelled out, buttons pressed)
+        let reduce = |lights: &mut Bits, pressed: &mut Bits, reduced: &[(usize, Bits, Bits)]| {
+            for (pivot, other_lights, other_pressed) in reduced {
+                if lights.contains(*pivot) {
+                    lights.toggle_all(other_lights);
+                    pressed.toggle_all(other_pressed);
+                }
+            }
+        };
+        for (i, button) in machine.button_bitset.iter().enumerate() {
+            let mut lights = button.clone();
+            let mut pressed = Bits::new(button_count);
+            pressed.toggle(i);
+            reduce(&mut lights, &mut pressed, &reduced);
+            match lights.first() {
+                Some(pivot) => reduced.push((pivot, lights, pressed)),
+                None => no_change.push((i, pressed)),
+            }
+        }
+
+        // The same reduction for the light diagram gives the buttons to press to get it,
+        // unless there are lights left that none of the buttons can get to
+        let mut lights = machine.light_diagram.clone();
+        let mut pressed = Bits::new(button_count);
+        reduce(&mut lights, &mut pressed, &reduced);
+        if lights.first().is_some() {
+            // if we're here, we're screwed
+            return Presses::Infeasible;
+        }
+
+        // That's one way to do it, but pressing any of the no change combinations as well gives
+        // the same lights, with maybe fewer buttons. Each of those has its own button, the one that
+        // was cancelled out, which none of the earlier ones have. With a bit more elimination,
+        // none of the others have it either, and neither do the presses we have so far. Then each
+        // combination that's mixed in adds its own button to the presses, and there's no need
+        // to try mixing in as many of them as the fewest presses we've found so far.
+        for j in 0..no_change.len() {
+            let (own, combination) = no_change[j].clone();
+            for (_, other) in no_change[j + 1..].iter_mut() {
+                if other.contains(own) {
+                    other.toggle_all(&combination);
+                }
+            }
+            if pressed.contains(own) {
+                pressed.toggle_all(&combination);
+            }
+        }
+        let mut fewest = pressed.clone();
+        mix_in(&no_change, &mut pressed, 0, 0, &mut fewest);
+        Presses::Buttons((0..button_count).map(|i| fewest.contains(i) as u64).collect())
+    }
+
+    fn press_for_joltage(&self, machine: &Machine) -> Presses {
+        // We need to go for math here, solving a system of linear equations
+        // using Gaussian elimination with back substitution, at least it started 
+        // like that. Luckily there today is google and AI nowadays to help with that... :D
+
+        // This finally working solution was proudly iterated into existence
+        // by me trying stuff, having Geimini suggest and refactor things, 
+        // optimizing stuff, rinse and repeat until it was first of all correct,
+        // and then also fast enough.
+        // And then it turned out to be a textbook integer linear programming problem, so now
+        // it's solved as one: minimize the total presses x (every press costs 1), with Ax = B,
+        // where A is the button wiring matrix and B is the joltage requirements.
+        // The requirements are u16s, so they only go up to 65535, and with the equations solved
+        // in whole numbers first, ones in the tens of thousands are still quick (see the tests).
+        let num_eqs = machine.joltage_requirements.len();
+        let mut program = IntegerProgram::new(&vec![1; machine.button_wiring.len()]);
+        for counter_idx in 0..num_eqs {
+            let wired: Vec<i64> = machine
+                .button_wiring
+                .iter()
+                .map(|wiring| wiring.contains(&counter_idx) as i64)
+                .collect();
+            program.constrain(&wired, Relation::Equal, machine.joltage_requirements[counter_idx] as i64);
+        }
+
+        // Every press of a button adds one to each of its counters, and they can't go back down,
+        // so a button can't be pressed more often than the lowest requirement of its counters.
+        // (A button that isn't wired to anything is never worth pressing at all.)
+        for (button, wiring) in machine.button_wiring.iter().enumerate() {
+            let max_presses = wiring
+                .iter()
+                .map(|&counter_idx| machine.joltage_requirements[counter_idx] as i64)
+                .min()
+                .unwrap_or(0);
+            program.at_most(button, max_presses);
+        }
+
+        match program.minimize() {
+            Some(solution) => Presses::Buttons(solution.values.iter().map(|&presses| presses as u64).collect()),
+            None => Presses::Infeasible,
+        }
+    }
+}
+
+// Try mixing in more of the no change combinations, from `start` on, on top of the `mixed` ones
+// that already are, and keep the fewest presses. Still a lot of ways when the fewest presses are
+// many, but only the ones that stand a chance, and never all of them.
+fn mix_in(no_change: &[(usize, Bits)], pressed: &mut Bits, start: usize, mixed: u64, fewest: &mut Bits) {
+    if pressed.count() < fewest.count() {
+        *fewest = pressed.clone();
+    }
+    // Another one presses its own button too, so it can't be fewer if that's already too many
+    if mixed + 1 >= fewest.count() {
+        return;
+    }
+    for (j, (_, combination)) in no_change.iter().enumerate().skip(start) {
+        pressed.toggle_all(combination);
+        mix_in(no_change, pressed, j + 1, mixed + 1, fewest);
+        pressed.toggle_all(combination);
+    }
+}
+
+#[cfg(test)]
+mod tests;
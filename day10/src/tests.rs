@@ -1,6 +1,157 @@
-// use super::*;
+use super::*;
 
-// #[test]
-// fn dummy_test() {
-//     assert_eq!(1 + 1, 2);
-// }
\ No newline at end of file
+// The example from the puzzle description
+const EXAMPLE: &str = include_str!("../test.txt");
+
+#[test]
+fn bad_buttons_are_errors() {
+    let error = Solver::parse("[.##.] (3) (1,x) {3,5,4,7}").err().unwrap();
+    assert_eq!((error.line, error.column, error.text.as_str()), (1, 15, "x"));
+
+    // Only 4 lights to wire the buttons to
+    let error = Solver::parse("[.##.] (3) (1,4) {3,5,4,7}").err().unwrap();
+    assert_eq!((error.column, error.text.as_str()), (15, "4"));
+}
+
+#[test]
+fn example_part1() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part1().total(), 7);
+}
+
+#[test]
+fn example_part2() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part2().total(), 33);
+}
+
+#[test]
+fn joltages_that_need_fractions_to_solve() {
+    // Every button adds to two of the three counters, so each counter is half of the sum
+    // of all of them minus the opposite one, which the elimination has to get exactly right
+    let solver = Solver::parse("[...] (0,1) (1,2) (0,2) {2,2,2}").unwrap();
+    assert_eq!(solver.part2().total(), 3);
+    let solver = Solver::parse("[...] (0,1) (1,2) (0,2) {3,3,2}").unwrap();
+    assert_eq!(solver.part2().total(), 4);
+
+    // An odd total can't be made with buttons that always add two at a time
+    let solver = Solver::parse("[...] (0,1) (1,2) (0,2) {1,1,1}").unwrap();
+    assert_eq!(solver.part2().total(), 0);
+    assert_eq!(solver.part2().presses(), [Presses::Infeasible]);
+}
+
+#[test]
+fn joltages_above_300() {
+    // The best is pressing the button for both counters as often as the first one allows
+    let solver = Solver::parse("[..] (0) (1) (0,1) {400,500}").unwrap();
+    assert_eq!(solver.part2().total(), 500);
+}
+
+#[test]
+fn joltages_in_the_tens_of_thousands() {
+    // Branching on one button at a time, this one never seemed to finish
+    let input = "[.........] (1,2,5,8) (1,4,6,7,8) (4,8) (0,2,5,7,8) (0,1,2,3,4,6,7,8) (2,4,7,8) (0,1,2,3,4,5,6,7,8) \
+                 (0,2,5,6) (0,1,2,6,8) (4,6) (1,2,3,4,5,7,8) {12378,16840,21334,5542,18241,13282,15710,14079,26207}";
+    let solver = Solver::parse(input).unwrap();
+    assert_eq!(solver.part2().total(), 29376);
+}
+
+#[test]
+fn more_lights_than_fit_in_a_number() {
+    // 11 lights, just over the old limit of 10
+    let solver = Solver::parse("[.........##] (10) (9) (0,9) (0,10) {1,1,1,1,1,1,1,1,1,1,1}").unwrap();
+    assert_eq!(solver.part1().total(), 2);
+
+    // and 70, which takes two 64 bit numbers, with the buttons wired across both of them
+    let lights = format!("[#{}#]", ".".repeat(68));
+    let joltages = format!("{{{}}}", vec!["1"; 70].join(","));
+    let input = format!("{} (0) (0,69) (1,68,69) (68) {}", lights, joltages);
+    let solver = Solver::parse(&input).unwrap();
+    assert_eq!(solver.part1().total(), 1);
+    let input = format!("{} (0) (1,68,69) (1,68) {}", lights, joltages);
+    let solver = Solver::parse(&input).unwrap();
+    assert_eq!(solver.part1().total(), 3);
+}
+
+#[test]
+fn lots_of_lights_and_buttons() {
+    // A chain of 99 buttons toggling two lights next to each other, any of the lights in
+    // between get toggled twice, but the ends can be done with just two buttons as well
+    let chain: Vec<String> = (0..99).map(|i| format!("({},{})", i, i + 1)).collect();
+    let input = format!("[#{}#] {} (0) (99) {{{}}}", ".".repeat(98), chain.join(" "), vec!["1"; 100].join(","));
+    let solver = Solver::parse(&input).unwrap();
+    assert_eq!(solver.part1().total(), 2);
+}
+
+#[test]
+fn lights_that_no_button_is_wired_to() {
+    let solver = Solver::parse("[#.] (1) {1,1}").unwrap();
+    assert_eq!(solver.part1().total(), 0);
+    assert_eq!(solver.part1().presses(), [Presses::Infeasible]);
+
+    // which is not the same as not having to press anything at all
+    let solver = Solver::parse("[..] (1) {0,0}").unwrap();
+    assert_eq!(solver.part1().presses(), [Presses::Buttons(vec![0])]);
+    assert_eq!(solver.part2().presses(), [Presses::Buttons(vec![0])]);
+}
+
+#[test]
+fn the_buttons_to_press() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    let lights = solver.part1();
+    let joltages = solver.part2();
+    for (i, machine) in solver.machines.iter().enumerate() {
+        // Pressing the buttons for the lights gets the lights right
+        let Presses::Buttons(presses) = &lights.presses()[i] else { panic!("machine {} can be done", i + 1) };
+        let mut lit = Bits::new(machine.joltage_requirements.len());
+        for (button, &count) in machine.button_bitset.iter().zip(presses) {
+            if count % 2 == 1 {
+                lit.toggle_all(button);
+            }
+        }
+        assert_eq!(lit, machine.light_diagram);
+
+        // and the ones for the joltages get those right
+        let Presses::Buttons(presses) = &joltages.presses()[i] else { panic!("machine {} can be done", i + 1) };
+        let mut joltage = vec![0; machine.joltage_requirements.len()];
+        for (wiring, &count) in machine.button_wiring.iter().zip(presses) {
+            for &counter in wiring {
+                joltage[counter] += count as u16;
+            }
+        }
+        assert_eq!(joltage, machine.joltage_requirements);
+    }
+    assert_eq!(lights.presses().iter().map(Presses::total).collect::<Vec<_>>(), vec![2, 3, 2]);
+    assert_eq!(joltages.presses().iter().map(Presses::total).collect::<Vec<_>>(), vec![10, 12, 11]);
+}
+
+#[test]
+fn explain_the_presses() {
+    let mut solver = Solver::parse("[#.] (0) (0,1) {3,1}\n[.#] (0) {1,1}").unwrap();
+    assert_eq!(format!("{:#}", solver.part2()), "3 (1 of 2 machines can't be done)");
+
+    let mut options = Options::new();
+    options.add("explain", None);
+    solver.configure(&mut options).unwrap();
+    assert_eq!(
+        format!("{:#}", solver.part1()),
+        "1 (1 of 2 machines can't be done)\nMachine 1: 1 presses, (0) x1\nMachine 2: can't be done"
+    );
+    assert_eq!(
+        format!("{:#}", solver.part2()),
+        "3 (1 of 2 machines can't be done)\nMachine 1: 3 presses, (0) x2, (0,1) x1\nMachine 2: can't be done"
+    );
+    // and the plain answer is just the number, to compare with the right answer
+    assert_eq!(solver.part2().to_string(), "3");
+}
+
+#[test]
+fn lots_of_ways_to_not_change_the_lights() {
+    // 80 buttons for only 3 lights leaves 77 combinations of buttons that don't change anything,
+    // way too many to try all the ways to mix them in
+    let buttons: Vec<&str> = ["(0)", "(1)", "(2)", "(0,2)", "(1,2)"].into_iter().cycle().take(80).collect();
+    let solver = Solver::parse(&format!("[#.#] {} {{1,1,1}}", buttons.join(" "))).unwrap();
+    assert_eq!(solver.part1().total(), 1);
+    let solver = Solver::parse(&format!("[###] {} {{1,1,1}}", buttons.join(" "))).unwrap();
+    assert_eq!(solver.part1().total(), 2);
+}
@@ -0,0 +1,100 @@
+use aoc_common::graph::count_paths;
+use aoc_common::{Day, Line, ParseError, lines};
+use std::collections::HashMap;
+
+// Only for the device names we know are fine, like "you" and "out"
+fn parse_device(s: &str) -> Device {
+    let chars: Vec<char> = s.chars().collect();
+    [chars[0], chars[1], chars[2]]
+}
+
+// Device names from the input are checked first, all of them are three characters
+fn read_device(line: &Line, s: &str) -> Result<Device, ParseError> {
+    match s.chars().collect::<Vec<char>>()[..] {
+        [a, b, c] => Ok([a, b, c]),
+        _ => Err(line.error(s, "device names are three characters")),
+    }
+}
+
+fn parse_input(input: &str) -> Result<HashMap<Device, Vec<Device>>, ParseError> {
+    let mut data: HashMap<Device, Vec<Device>> = HashMap::new();
+
+    // Parse each line of the input accordingly
+    for line in lines(Solver::DAY, input) {
+        let (node, outputs) = line
+            .text
+            .split_once(": ")
+            .ok_or_else(|| line.error(line.text, "expected a device and its outputs, like 'aaa: you hhh'"))?;
+        let node = read_device(&line, node)?;
+        let outputs = outputs
+            .split_whitespace()
+            .map(|s| read_device(&line, s))
+            .collect::<Result<Vec<Device>, ParseError>>()?;
+        data.insert(node, outputs);
+    }
+
+    Ok(data)
+}
+
+type Device = [char; 3];
+
+pub struct Solver {
+    graph: HashMap<Device, Vec<Device>>,
+}
+
+impl Day for Solver {
+    const DAY: u8 = 11;
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver { graph: parse_input(input)? })
+    }
+
+    fn part1(&self) -> u64 {
+        self.count_the_paths(parse_device("you"), parse_device("out"), parse_device("   "))
+    }
+
+    fn part2(&self) -> u64 {
+        // We want to get from svr to out, but via the special devices fft and dac (in any order)
+        // So we count for svr->fft->dac->out and svr->dac->fft->out and add them together
+        // When counting each of those, we need to avoid going through the other of the special devices
+
+        // svr -> fft -> dac -> out
+        let svr_to_fft = self.count_the_paths(parse_device("svr"), parse_device("fft"), parse_device("dac"));
+        let fft_to_dac = self.count_the_paths(parse_device("fft"), parse_device("dac"), parse_device("   "));
+        let dac_to_out = self.count_the_paths(parse_device("dac"), parse_device("out"), parse_device("fft"));
+        let count_svr_fft_dac_out = svr_to_fft * fft_to_dac * dac_to_out;
+
+        // svr -> dac -> fft -> out
+        let svr_to_dac = self.count_the_paths(parse_device("svr"), parse_device("dac"), parse_device("fft"));
+        let dac_to_fft = self.count_the_paths(parse_device("dac"), parse_device("fft"), parse_device("   "));
+        let fft_to_out = self.count_the_paths(parse_device("fft"), parse_device("out"), parse_device("dac"));
+        let count_svr_dac_fft_out = svr_to_dac * dac_to_fft * fft_to_out;
+
+        count_svr_fft_dac_out + count_svr_dac_fft_out
+    }
+}
+
+impl Solver {
+    fn count_the_paths(&self, from: Device, to: Device, avoid: Device) -> u64 {
+        // We want to avoid this one
+        if from == avoid {
+            return 0;
+        }
+
+        count_paths(
+            from,
+            |&device| device == to,
+            |device| {
+                // Not going through the one to avoid, and no outputs means a dead end
+                let outputs = self.graph.get(device).map_or(&[][..], |outputs| outputs.as_slice());
+                outputs.iter().copied().filter(move |&output| output != avoid)
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests;
\ No newline at end of file
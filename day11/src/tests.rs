@@ -1,6 +1,26 @@
-// use super::*;
+use super::*;
 
-// #[test]
-// fn dummy_test() {
-//     assert_eq!(1 + 1, 2);
-// }
\ No newline at end of file
+// The examples from the puzzle description, one for each part
+const EXAMPLE_PART1: &str = include_str!("../test_part1.txt");
+const EXAMPLE_PART2: &str = include_str!("../test_part2.txt");
+
+#[test]
+fn short_device_names_are_errors() {
+    let error = Solver::parse("you: bbb\nbbb: ou\n").err().unwrap();
+    assert_eq!((error.line, error.column, error.text.as_str()), (2, 6, "ou"));
+
+    let error = Solver::parse("you bbb\n").err().unwrap();
+    assert_eq!((error.line, error.column), (1, 1));
+}
+
+#[test]
+fn example_part1() {
+    let solver = Solver::parse(EXAMPLE_PART1).unwrap();
+    assert_eq!(solver.part1(), 5);
+}
+
+#[test]
+fn example_part2() {
+    let solver = Solver::parse(EXAMPLE_PART2).unwrap();
+    assert_eq!(solver.part2(), 2);
+}
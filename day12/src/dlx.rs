@@ -0,0 +1,308 @@
+// Knuth's Algorithm X with Dancing Links, for exact cover problems: pick a set of rows
+// so that each primary column is covered exactly as many times as it should be (usually
+// once), and each secondary column at most once.
+//
+// For the presents, the primary columns are the shapes, to be covered once for each
+// present of that shape, and the secondary columns are the cells of the region, which
+// can only take one present but may stay empty (unless the presents fill up the whole
+// region, then the cells are primary too). Covering a column more than once is
+// what keeps identical presents from being tried in every possible order: the rows of
+// a column are only ever picked in the order they're in.
+
+// The nodes are all in arrays, with indexes instead of pointers: the root is node 0,
+// then come the column headers, and then the nodes of the rows
+const ROOT: usize = 0;
+
+pub struct ExactCover {
+    // Every node is in two circular lists, one for its row and one for its column
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    column: Vec<usize>, // the header node of the column of each node
+    row: Vec<usize>,    // the row each node is in (not used for the headers)
+
+    // For each header (indexed by node, so 0 is the root)
+    len: Vec<usize>,   // how many rows are still in the column
+    bound: Vec<usize>, // how many more times the column has to be covered, 0 for secondary
+
+    primary: usize, // the headers of the primary columns are 1..=primary
+    rows: usize,
+    nodes: u64,     // rows picked while searching, to see how much searching it took
+    gave_up: bool,  // because it was told to
+}
+
+impl ExactCover {
+    // The primary columns come first, with how many times each should be covered,
+    // and then the secondary ones
+    pub fn new(bounds: &[usize], secondary: usize) -> Self {
+        let headers = bounds.len() + secondary + 1;
+        let mut cover = ExactCover {
+            left: (0..headers).collect(),
+            right: (0..headers).collect(),
+            up: (0..headers).collect(),
+            down: (0..headers).collect(),
+            column: (0..headers).collect(),
+            row: vec![0; headers],
+            len: vec![0; headers],
+            bound: vec![0; headers],
+            primary: bounds.len(),
+            rows: 0,
+            nodes: 0,
+            gave_up: false,
+        };
+
+        // Only the primary columns that still need covering are linked to the root, those are
+        // the ones to choose from. The others are on their own, but rows can still cover them.
+        for (i, &bound) in bounds.iter().enumerate() {
+            let header = i + 1;
+            cover.bound[header] = bound;
+            if bound > 0 {
+                cover.left[header] = cover.left[ROOT];
+                cover.right[header] = ROOT;
+                cover.right[cover.left[ROOT]] = header;
+                cover.left[ROOT] = header;
+            }
+        }
+
+        cover
+    }
+
+    // Add a row covering the given columns (primary and secondary numbered together),
+    // the rows are numbered in the order they're added
+    pub fn add_row(&mut self, columns: &[usize]) -> usize {
+        let row = self.rows;
+        self.rows += 1;
+
+        let first = self.left.len();
+        for (i, &column) in columns.iter().enumerate() {
+            let header = column + 1;
+            let node = first + i;
+
+            // at the end of the row, which is circular
+            self.left.push(if i == 0 { node } else { node - 1 });
+            self.right.push(first);
+            if i > 0 {
+                self.right[node - 1] = node;
+                self.left[first] = node;
+            }
+
+            // and at the bottom of the column
+            self.up.push(self.up[header]);
+            self.down.push(header);
+            self.down[self.up[header]] = node;
+            self.up[header] = node;
+
+            self.column.push(header);
+            self.row.push(row);
+            self.len[header] += 1;
+        }
+
+        row
+    }
+
+    // Find a set of rows that covers everything as it should, the rows in the order
+    // they were picked, or None if there's no such set (or if we gave up looking).
+    // Before picking each row, give_up is asked if it's time to stop, with how many
+    // rows were picked so far.
+    pub fn solve(&mut self, mut give_up: impl FnMut(u64) -> bool) -> Option<Vec<usize>> {
+        self.gave_up = false;
+        // Primary columns that are already covered often enough can't take any rows at all
+        let done: Vec<usize> = (1..=self.primary).filter(|&header| self.bound[header] == 0).collect();
+        for &header in done.iter() {
+            self.cover(header);
+        }
+
+        let mut solution = Vec::new();
+        let found = self.search(&mut solution, &mut give_up);
+
+        for &header in done.iter().rev() {
+            self.uncover(header);
+        }
+        found.then_some(solution)
+    }
+
+    // How many rows were picked while solving, all of the times it was solved
+    pub fn nodes(&self) -> u64 {
+        self.nodes
+    }
+
+    // Did the last solve give up, before it could find out if there's a solution?
+    pub fn gave_up(&self) -> bool {
+        self.gave_up
+    }
+
+    fn search(&mut self, solution: &mut Vec<usize>, give_up: &mut impl FnMut(u64) -> bool) -> bool {
+        // All of the primary columns covered, we done!
+        let Some(column) = self.choose_column() else {
+            return true;
+        };
+        // Not enough rows left in the column to cover it often enough
+        if self.len[column] < self.bound[column] {
+            return false;
+        }
+
+        // The last time the column is covered, it's taken out completely like in the classic
+        // algorithm. Before that, each row that's picked stays out until we're back here,
+        // so that the next times only rows further down the column can be picked.
+        self.bound[column] -= 1;
+        let last = self.bound[column] == 0;
+        if last {
+            self.cover(column);
+        }
+
+        let mut found = false;
+        let mut picked = Vec::new();
+        let mut node = self.down[column];
+        while node != column {
+            if give_up(self.nodes) {
+                self.gave_up = true;
+                break;
+            }
+            let next = self.down[node];
+            if !last {
+                self.hide(node);
+            }
+            self.cover_row(node);
+            self.nodes += 1;
+            solution.push(self.row[node]);
+            found = self.search(solution, give_up);
+            if !found {
+                solution.pop();
+            }
+            self.uncover_row(node);
+            if !last {
+                picked.push(node);
+            }
+            // when giving up, everything is still put back like it was on the way out
+            if found || self.gave_up {
+                break;
+            }
+            node = next;
+        }
+
+        // Put everything back like it was, so the caller can keep on searching
+        for &node in picked.iter().rev() {
+            self.unhide(node);
+        }
+        if last {
+            self.uncover(column);
+        }
+        self.bound[column] += 1;
+
+        found
+    }
+
+    // The primary column with the fewest choices left, to keep the search tree small
+    fn choose_column(&self) -> Option<usize> {
+        let mut best: Option<(usize, usize)> = None;
+        let mut header = self.right[ROOT];
+        while header != ROOT {
+            // how many ways there are to pick the next row of the column, kind of
+            let choices = (self.len[header] + 1).saturating_sub(self.bound[header]);
+            if best.is_none_or(|(fewest, _)| choices < fewest) {
+                best = Some((choices, header));
+                if choices == 0 {
+                    break; // can't do better than a dead end
+                }
+            }
+            header = self.right[header];
+        }
+        best.map(|(_, header)| header)
+    }
+
+    // Take a column out, and every row in it out of the other columns
+    fn cover(&mut self, header: usize) {
+        self.right[self.left[header]] = self.right[header];
+        self.left[self.right[header]] = self.left[header];
+        let mut node = self.down[header];
+        while node != header {
+            let mut other = self.right[node];
+            while other != node {
+                self.unlink_vertically(other);
+                other = self.right[other];
+            }
+            node = self.down[node];
+        }
+    }
+
+    // Exactly the reverse of cover, in the reverse order
+    fn uncover(&mut self, header: usize) {
+        let mut node = self.up[header];
+        while node != header {
+            let mut other = self.left[node];
+            while other != node {
+                self.relink_vertically(other);
+                other = self.left[other];
+            }
+            node = self.up[node];
+        }
+        self.right[self.left[header]] = header;
+        self.left[self.right[header]] = header;
+    }
+
+    // Cover all the other columns of the row of the node, or just count down
+    // the primary ones that need to be covered more times than this
+    fn cover_row(&mut self, node: usize) {
+        let mut other = self.right[node];
+        while other != node {
+            let header = self.column[other];
+            if header <= self.primary {
+                self.bound[header] -= 1;
+            }
+            if self.bound[header] == 0 {
+                self.cover(header);
+            }
+            other = self.right[other];
+        }
+    }
+
+    fn uncover_row(&mut self, node: usize) {
+        let mut other = self.left[node];
+        while other != node {
+            let header = self.column[other];
+            if self.bound[header] == 0 {
+                self.uncover(header);
+            }
+            if header <= self.primary {
+                self.bound[header] += 1;
+            }
+            other = self.left[other];
+        }
+    }
+
+    // Take the whole row of the node out of all of its columns
+    fn hide(&mut self, node: usize) {
+        self.unlink_vertically(node);
+        let mut other = self.right[node];
+        while other != node {
+            self.unlink_vertically(other);
+            other = self.right[other];
+        }
+    }
+
+    fn unhide(&mut self, node: usize) {
+        let mut other = self.left[node];
+        while other != node {
+            self.relink_vertically(other);
+            other = self.left[other];
+        }
+        self.relink_vertically(node);
+    }
+
+    // The actual dancing of the links
+    fn unlink_vertically(&mut self, node: usize) {
+        self.down[self.up[node]] = self.down[node];
+        self.up[self.down[node]] = self.up[node];
+        self.len[self.column[node]] -= 1;
+    }
+
+    fn relink_vertically(&mut self, node: usize) {
+        self.down[self.up[node]] = node;
+        self.up[self.down[node]] = node;
+        self.len[self.column[node]] += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests;
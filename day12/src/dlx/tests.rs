@@ -0,0 +1,104 @@
+use super::*;
+
+// The rows of a solution, sorted, to compare them without caring about the order they were picked in
+fn solve(cover: &mut ExactCover) -> Option<Vec<usize>> {
+    cover.solve(|_| false).map(|mut rows| {
+        rows.sort();
+        rows
+    })
+}
+
+#[test]
+fn knuths_example() {
+    // The one from the Dancing Links paper, with columns A to G
+    let mut cover = ExactCover::new(&[1; 7], 0);
+    cover.add_row(&[2, 4, 5]);
+    cover.add_row(&[0, 3, 6]);
+    cover.add_row(&[1, 2, 5]);
+    cover.add_row(&[0, 3]);
+    cover.add_row(&[1, 6]);
+    cover.add_row(&[3, 4, 6]);
+    assert_eq!(solve(&mut cover), Some(vec![0, 3, 4]));
+    // and it can be solved again, since everything is put back like it was
+    assert_eq!(solve(&mut cover), Some(vec![0, 3, 4]));
+}
+
+#[test]
+fn no_exact_cover() {
+    let mut cover = ExactCover::new(&[1; 3], 0);
+    cover.add_row(&[0, 1]);
+    cover.add_row(&[1, 2]);
+    assert_eq!(solve(&mut cover), None);
+}
+
+#[test]
+fn secondary_columns_may_stay_empty() {
+    // Columns 0 and 1 are primary, 2 and 3 secondary
+    let mut cover = ExactCover::new(&[1, 1], 2);
+    cover.add_row(&[0, 2, 3]);
+    cover.add_row(&[1, 2]);
+    cover.add_row(&[1, 3]);
+    assert_eq!(solve(&mut cover), None);
+    cover.add_row(&[0, 2]);
+    assert_eq!(solve(&mut cover), Some(vec![2, 3]));
+}
+
+#[test]
+fn columns_covered_more_than_once() {
+    // Three dominoes in a row of 6 cells (secondary), any of the 5 positions
+    let mut cover = ExactCover::new(&[3], 6);
+    for x in 0..5 {
+        cover.add_row(&[0, 1 + x, 2 + x]);
+    }
+    assert_eq!(solve(&mut cover), Some(vec![0, 2, 4]));
+
+    // but not four of them
+    let mut cover = ExactCover::new(&[4], 6);
+    for x in 0..5 {
+        cover.add_row(&[0, 1 + x, 2 + x]);
+    }
+    assert_eq!(solve(&mut cover), None);
+}
+
+#[test]
+fn primary_cells_and_columns_covered_more_than_once() {
+    // Now the 6 cells have to be covered, by two dominoes and two monominoes
+    let mut cover = ExactCover::new(&[2, 2, 1, 1, 1, 1, 1, 1], 0);
+    for x in 0..5 {
+        cover.add_row(&[0, 2 + x, 3 + x]);
+    }
+    for x in 0..6 {
+        cover.add_row(&[1, 2 + x]);
+    }
+    let rows = solve(&mut cover).unwrap();
+    assert_eq!(rows.len(), 4);
+    let mut cells: Vec<usize> = rows.iter().flat_map(|&row| if row < 5 { vec![row, row + 1] } else { vec![row - 5] }).collect();
+    cells.sort();
+    assert_eq!(cells, vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn columns_covered_no_times_at_all() {
+    // The row covering column 1 can't be used, since column 1 needs no covering
+    let mut cover = ExactCover::new(&[1, 0], 1);
+    cover.add_row(&[0, 1]);
+    assert_eq!(solve(&mut cover), None);
+    cover.add_row(&[0, 2]);
+    assert_eq!(solve(&mut cover), Some(vec![1]));
+}
+
+#[test]
+fn give_up_at_the_limit() {
+    // 6 dominoes in a row of 13 cells, which takes more than 3 of them to find out
+    let mut cover = ExactCover::new(&[6], 13);
+    for x in 0..12 {
+        cover.add_row(&[0, 1 + x, 2 + x]);
+    }
+    assert_eq!(cover.solve(|nodes| nodes >= 3), None);
+    assert!(cover.gave_up());
+    assert_eq!(cover.nodes(), 3);
+
+    // and without the limit, everything was put back so it can still be solved
+    assert!(cover.solve(|_| false).is_some());
+    assert!(!cover.gave_up());
+}
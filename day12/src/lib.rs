@@ -0,0 +1,768 @@
+mod dlx;
+
+use aoc_common::bitgrid::BitGrid;
+use aoc_common::grid::neighbours4;
+use aoc_common::{Day, Options, ParseError, lines};
+use dlx::ExactCover;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Each row of a shape is a single u64, and once a shape is rotated its columns
+// are the rows, so it has to fit in 64 both ways
+const MAX_SHAPE_SIZE: usize = 64;
+
+// A shape starts with a header line with its index, like "4:", as opposed to
+// a region, like "12x5: 1 0 1 0 2 2"
+fn shape_index(text: &str) -> Option<&str> {
+    let index = text.trim().strip_suffix(':')?;
+    (!index.is_empty() && index.bytes().all(|b| b.is_ascii_digit())).then_some(index)
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Shape>, Vec<Region>), ParseError> {
+    let mut shapes: Vec<Shape> = Vec::new();
+    let mut regions: Vec<Region> = Vec::new();
+
+    // Parse the input line by line accordingly
+    let mut lines = lines(Solver::DAY, input).peekable();
+
+    while let Some(line) = lines.next() {
+        if line.text.trim().is_empty() {
+            continue;
+        }
+
+        // First come the shapes, as many as there are, each one with its header
+        if let Some(digits) = shape_index(line.text) {
+            let header = line;
+            if !regions.is_empty() {
+                return Err(header.error(header.text, "the shapes should all come before the regions"));
+            }
+            let index: usize = header.parse(digits, "shape index")?;
+            if index != shapes.len() {
+                return Err(header.error(digits, format!("expected shape {} here", shapes.len())));
+            }
+
+            // read the lines of the shape, up to the next empty line (or header, or region)
+            let mut shape_lines = String::new();
+            while let Some(line) = lines.next_if(|line| !line.text.trim().is_empty() && !line.text.contains(':')) {
+                if line.text.chars().count() > MAX_SHAPE_SIZE {
+                    return Err(line.error(line.text, format!("shapes can be at most {} wide", MAX_SHAPE_SIZE)));
+                }
+                if let Some((i, c)) = line.text.char_indices().find(|&(_, c)| c != '#' && c != '.') {
+                    return Err(line.error(&line.text[i..i + c.len_utf8()], "expected '#' or '.'"));
+                }
+                shape_lines.push_str(line.text);
+                shape_lines.push('\n');
+            }
+            let shape = Shape::from_str(&shape_lines);
+            if shape.size == 0 {
+                return Err(header.error(header.text, "the shape has no '#' at all"));
+            }
+            if shape.height() > MAX_SHAPE_SIZE {
+                return Err(header.error(header.text, format!("shapes can be at most {} high", MAX_SHAPE_SIZE)));
+            }
+            shapes.push(shape);
+            continue;
+        }
+
+        // Next up we read the region definitions
+        let (dimensions, counts) = line
+            .text
+            .split_once(':')
+            .ok_or_else(|| line.error(line.text, "invalid region definition, expected WxH: counts"))?;
+        let (width_str, height_str) = dimensions
+            .split_once('x')
+            .ok_or_else(|| line.error(dimensions, "invalid region dimensions, expected WxH"))?;
+        let width: usize = line.parse(width_str.trim(), "width")?;
+        let height: usize = line.parse(height_str.trim(), "height")?;
+        let shape_quantity = counts
+            .split_whitespace()
+            .map(|s| line.parse(s, "shape count"))
+            .collect::<Result<Vec<usize>, ParseError>>()?;
+        // One count for each of the shapes, no more, no less
+        if shape_quantity.len() != shapes.len() {
+            return Err(line.error(
+                counts,
+                format!("expected {} shape counts, one for each shape, not {}", shapes.len(), shape_quantity.len()),
+            ));
+        }
+        let region = Region {
+            width,
+            height,
+            shape_quantity,
+        };
+        regions.push(region);
+    }
+
+    Ok((shapes, regions))
+}
+
+struct Region {
+    width: usize,
+    height: usize,
+    shape_quantity: Vec<usize>, // how many of each shape, in the order of the shapes
+}
+
+// Any polyomino-ish shape, normalized so that it touches the top and left edges of its box.
+// That way two shapes are the same shape exactly when they are equal, wherever they came from.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Shape {
+    rows: Vec<u64>, // bit pattern for each row of the box, bit 0 being the leftmost cell
+                    // (we use u64 to be able to shift bits into the u64 words of the grid...)
+    width: usize,   // width of the box, the height is the number of rows
+    size: usize,    // number of # in the box
+}
+
+impl Shape {
+    // parse a shape from lines of # and .
+    fn from_str(s: &str) -> Self {
+        let cells = s.lines().enumerate().flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|&(_, char)| char == '#')
+                .map(move |(x, _)| (x, y))
+        });
+        Shape::from_cells(cells)
+    }
+
+    // a shape from the (x, y) of its cells, moved up and left as far as possible
+    fn from_cells(cells: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        let cells: Vec<(usize, usize)> = cells.into_iter().collect();
+        let min_x = cells.iter().map(|&(x, _)| x).min().unwrap_or(0);
+        let min_y = cells.iter().map(|&(_, y)| y).min().unwrap_or(0);
+        let width = cells.iter().map(|&(x, _)| x - min_x + 1).max().unwrap_or(0);
+        let height = cells.iter().map(|&(_, y)| y - min_y + 1).max().unwrap_or(0);
+
+        let mut rows = vec![0u64; height];
+        for &(x, y) in cells.iter() {
+            rows[y - min_y] |= 1 << (x - min_x);  // set the bit for the #
+        }
+
+        Shape { rows, width, size: cells.len() }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    // the (x, y) of each # in the box
+    fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.rows.iter().enumerate().flat_map(move |(y, &row)| {
+            (0..self.width).filter(move |&x| row & (1 << x) != 0).map(move |x| (x, y))
+        })
+    }
+
+    // wee need to rotate the box 90 degrees clockwise
+    fn rotate(&self) -> Shape {
+        let height = self.height();
+        Shape::from_cells(self.cells().map(|(x, y)| (height - 1 - y, x)))
+    }
+
+    // we also need to be able to flip the box horizontally
+    fn flip(&self) -> Shape {
+        Shape::from_cells(self.cells().map(|(x, y)| (self.width - 1 - x, y)))
+    }
+
+    // let's generate all variants of this shape (4 rotations, each flipped and unflipped))
+    fn generate_variants(&self) -> Vec<Shape> {
+        let mut variants = Vec::new();
+        let mut current = self.clone();
+
+        for _ in 0..4 {
+            variants.push(current.flip());
+            let rotated = current.rotate();
+            variants.push(current);
+            current = rotated;
+        }
+
+        // Get rid of any duplicates, which is easy since all of them are normalized
+        variants.sort();
+        variants.dedup();
+
+        variants
+    }
+}
+
+// Where one of the presents went: which shape it is, which of the variants of the shape,
+// and where the top left corner of its box is in the region
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    pub shape: usize,
+    pub variant: usize,
+    pub x: usize,
+    pub y: usize,
+}
+
+// What decided if the presents fit in a region or not
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    Oversized, // the presents take up more cells than there are in the region
+    Slots,     // there's a separate square slot for each present, so they fit for sure
+    // we had to actually try, placing presents this many times, and cutting the search
+    // short this many times because of gaps too small for any of the presents left
+    Search { fits: bool, nodes: u64, dead_ends: u64 },
+    // we gave up after placing presents this many times, see --node-limit and --timeout
+    Undecided { nodes: u64 },
+}
+
+impl Decision {
+    pub fn fits(&self) -> bool {
+        match self {
+            Decision::Oversized | Decision::Undecided { .. } => false,
+            Decision::Slots => true,
+            Decision::Search { fits, .. } => *fits,
+        }
+    }
+}
+
+impl fmt::Display for Decision {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Decision::Oversized => write!(f, "oversized, the presents take up more room than there is"),
+            Decision::Slots => write!(f, "fits, with a slot for each present"),
+            Decision::Search { fits, nodes, dead_ends } => {
+                write!(f, "{} after placing {} presents", if *fits { "fits" } else { "no solution" }, nodes)?;
+                if *dead_ends > 0 {
+                    write!(f, " ({} dead ends with too small gaps)", dead_ends)?;
+                }
+                Ok(())
+            }
+            Decision::Undecided { nodes } => write!(f, "undecided, gave up after placing {} presents", nodes),
+        }
+    }
+}
+
+// How much searching each region gets, with --node-limit and --timeout,
+// so that a single nasty region can't keep us busy forever
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Budget {
+    nodes: Option<u64>,
+    time: Option<Duration>,
+}
+
+impl Budget {
+    // The limit for a region starting to be searched right now
+    fn start(&self) -> Limit {
+        Limit {
+            nodes: self.nodes,
+            deadline: self.time.map(|time| Instant::now() + time),
+        }
+    }
+}
+
+struct Limit {
+    nodes: Option<u64>,
+    deadline: Option<Instant>,
+}
+
+impl Limit {
+    // Time to give up, instead of placing another present after this many? The clock is
+    // looked at every time, since the work between two presents can be anything from nothing
+    // at all to trying every position in a big region.
+    fn reached(&self, nodes: u64) -> bool {
+        self.nodes.is_some_and(|limit| nodes >= limit) || self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+// The answer for part 1 is the number of regions that can fit their presents, but
+// let's also keep some stats on how the regions were decided
+pub struct Packing {
+    regions: usize,
+    count: u64,
+    count_oversized: u64,
+    count_slots: u64,
+    count_no_solution: u64,
+    count_undecided: u64,
+    decisions: Vec<Decision>,                // for each region, what decided it
+    placements: Vec<Option<Vec<Placement>>>, // for each region, where the presents went if they fit
+    report: Option<String>,                  // how each region was decided, with --report or --render
+}
+
+impl Packing {
+    pub fn decisions(&self) -> &[Decision] {
+        &self.decisions
+    }
+
+    // For each region, where the presents went, or None if they didn't fit
+    pub fn placements(&self) -> &[Option<Vec<Placement>>] {
+        &self.placements
+    }
+}
+
+impl fmt::Display for Packing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.count)?;
+        // The stats are only for the alternate {:#} form
+        if f.alternate() {
+            write!(
+                f,
+                " (of {} regions, {} oversized, {} in slots, {} with no solution",
+                self.regions, self.count_oversized, self.count_slots, self.count_no_solution
+            )?;
+            // only with a budget can there be any of these
+            if self.count_undecided > 0 {
+                write!(f, ", {} undecided", self.count_undecided)?;
+            }
+            write!(f, ")")?;
+            if let Some(report) = &self.report {
+                write!(f, "\n{}", report.trim_end())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// How to find out if the presents fit in a region, chosen with --backend
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Backend {
+    #[default]
+    Backtracking, // trying every position for every present, one after the other
+    Dlx,          // as an exact cover problem, with Dancing Links
+}
+
+impl FromStr for Backend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "backtracking" => Ok(Backend::Backtracking),
+            "dlx" => Ok(Backend::Dlx),
+            _ => Err("expected backtracking or dlx".to_string()),
+        }
+    }
+}
+
+pub struct Solver {
+    regions: Vec<Region>,
+    shape_variants: Vec<Vec<Shape>>,
+    backend: Backend,
+    jobs: usize,  // how many threads to check regions with
+    budget: Budget,
+    report: bool, // how each of the regions was decided
+    render: bool, // and the drawings of the ones that fit
+}
+
+impl Day for Solver {
+    const DAY: u8 = 12;
+
+    type Answer1 = Packing;
+    type Answer2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (shapes, regions) = parse_input(input)?;
+        Ok(Solver::new(&shapes, regions))
+    }
+
+    fn part1(&self) -> Packing {
+        let mut count: u64 = 0;
+        let mut count_oversized = 0;
+        let mut count_slots = 0;
+        let mut count_no_solution = 0;
+        let mut count_undecided = 0;
+        let mut decisions = Vec::new();
+        let mut placements = Vec::new();
+
+        // Check all of the regions and count how many can fit the presents, in the order
+        // of the regions, however many threads it took to check them
+        for (decision, fit) in self.pack_regions() {
+            match decision {
+                Decision::Oversized => count_oversized += 1,
+                Decision::Slots => count_slots += 1,
+                Decision::Search { fits: false, .. } => count_no_solution += 1,
+                Decision::Search { fits: true, .. } => {}
+                Decision::Undecided { .. } => count_undecided += 1,
+            }
+            if decision.fits() {
+                count += 1;
+            }
+            decisions.push(decision);
+            placements.push(fit);
+        }
+
+        let report = (self.report || self.render).then(|| self.report_regions(&decisions, &placements));
+
+        // What in the wat! With the actual input, it would've been enough with just
+        // the oversize check... o_O
+        Packing {
+            regions: self.regions.len(),
+            count,
+            count_oversized,
+            count_slots,
+            count_no_solution,
+            count_undecided,
+            decisions,
+            placements,
+            report,
+        }
+    }
+
+    fn part2(&self) -> &'static str {
+        "There is no part 2"
+    }
+
+    // --backend backtracking|dlx, --jobs N, --node-limit N, --timeout MS, --report and --render
+    fn configure(&mut self, options: &mut Options) -> Result<(), String> {
+        if let Some(backend) = options.value("backend")? {
+            self.backend = backend;
+        }
+        if let Some(jobs) = options.value("jobs")? {
+            if jobs == 0 {
+                return Err("--jobs needs at least 1 thread".to_string());
+            }
+            self.jobs = jobs;
+        }
+        if let Some(nodes) = options.value("node-limit")? {
+            self.budget.nodes = Some(nodes);
+        }
+        if let Some(milliseconds) = options.value("timeout")? {
+            self.budget.time = Some(Duration::from_millis(milliseconds));
+        }
+        if options.flag("report")? {
+            self.report = true;
+        }
+        if options.flag("render")? {
+            self.render = true;
+        }
+        Ok(())
+    }
+}
+
+impl Solver {
+    fn new(shapes: &[Shape], regions: Vec<Region>) -> Self {
+        let mut shape_variants = Vec::new();
+        for shape in shapes {
+            shape_variants.push(shape.generate_variants());
+        }
+        Solver {
+            regions,
+            shape_variants,
+            backend: Backend::default(),
+            jobs: thread::available_parallelism().map_or(1, |n| n.get()),
+            budget: Budget::default(),
+            report: false,
+            render: false,
+        }
+    }
+
+    // Pack all of the regions, spread over the threads. Some regions take a lot longer than
+    // others, so instead of splitting them up front, each thread just takes the next one that's
+    // left. The results go in the order of the regions, whichever thread did them.
+    fn pack_regions(&self) -> Vec<(Decision, Option<Vec<Placement>>)> {
+        let jobs = self.jobs.min(self.regions.len());
+        if jobs <= 1 {
+            return self.regions.iter().map(|region| self.pack(region)).collect();
+        }
+
+        let next = AtomicUsize::new(0);
+        let mut results: Vec<Option<(Decision, Option<Vec<Placement>>)>> = vec![None; self.regions.len()];
+        thread::scope(|scope| {
+            let threads: Vec<_> = (0..jobs)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut packed = Vec::new();
+                        loop {
+                            let i = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(region) = self.regions.get(i) else {
+                                return packed;
+                            };
+                            packed.push((i, self.pack(region)));
+                        }
+                    })
+                })
+                .collect();
+            for thread in threads {
+                for (i, result) in thread.join().expect("a thread packing regions panicked") {
+                    results[i] = Some(result);
+                }
+            }
+        });
+        results.into_iter().map(|result| result.expect("every region is packed")).collect()
+    }
+
+    // Do the presents fit in the region, and if so, where do they go?
+    fn pack(&self, region: &Region) -> (Decision, Option<Vec<Placement>>) {
+        // Leave early if there is not enought space at all
+        if self.total_present_size(region) > region.width * region.height {
+            return (Decision::Oversized, None);
+        }
+        // Or if there's so much space that they don't even have to fit together
+        if let Some(placements) = self.slots(region) {
+            return (Decision::Slots, Some(placements));
+        }
+
+        match self.backend {
+            Backend::Backtracking => self.backtrack(region),
+            Backend::Dlx => self.exact_cover(region),
+        }
+    }
+
+    fn total_present_size(&self, region: &Region) -> usize {
+        let sizes = self.shape_variants.iter().map(|variants| variants[0].size);
+        region.shape_quantity.iter().zip(sizes).map(|(count, size)| count * size).sum()
+    }
+
+    // With square slots as big as the biggest shape, each present can go in a slot of its own,
+    // without having to think about how they could fit together. The slots are 3x3 for the
+    // actual input, where that's true for all of the regions that aren't oversized.
+    fn slots(&self, region: &Region) -> Option<Vec<Placement>> {
+        let presents: Vec<usize> = region
+            .shape_quantity
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &count)| std::iter::repeat_n(i, count))
+            .collect();
+        let slot = presents
+            .iter()
+            .map(|&i| self.shape_variants[i][0].width.max(self.shape_variants[i][0].height()))
+            .max()
+            .unwrap_or(1); // no presents at all, that fits anywhere
+        let columns = region.width / slot;
+        if columns * (region.height / slot) < presents.len() {
+            return None;
+        }
+
+        let placements = presents.iter().enumerate().map(|(n, &shape)| Placement {
+            shape,
+            variant: 0,
+            x: n % columns * slot,
+            y: n / columns * slot,
+        });
+        Some(placements.collect())
+    }
+
+    fn backtrack(&self, region: &Region) -> (Decision, Option<Vec<Placement>>) {
+        let mut present_list = Vec::new();
+        for (i, &count) in region.shape_quantity.iter().enumerate() {
+            for _ in 0..count {
+                present_list.push(i);
+            }
+        }
+
+        // sort the shape list by area descending for hopefully a bit more luck with the placement...
+        // (the sort is stable, so presents of the same shape stay together)
+        present_list.sort_by_key(|&i| usize::MAX - self.shape_variants[i][0].size);
+
+        // how much room the presents still need, from each one on
+        let mut remaining = vec![0; present_list.len() + 1];
+        for i in (0..present_list.len()).rev() {
+            remaining[i] = remaining[i + 1] + self.shape_variants[present_list[i]][0].size;
+        }
+
+        // the last one is the smallest one
+        let smallest = present_list.last().map_or(0, |&i| self.shape_variants[i][0].size);
+        let mut search = Search {
+            grid: BitGrid::new(region.width, region.height),
+            presents: present_list,
+            remaining,
+            smallest,
+            placements: Vec::new(),
+            dead: 0,
+            limit: self.budget.start(),
+            nodes: 0,
+            dead_ends: 0,
+            gave_up: false,
+        };
+        let fits = self.fit_the_presents(&mut search);
+        if search.gave_up {
+            return (Decision::Undecided { nodes: search.nodes }, None);
+        }
+        let decision = Decision::Search { fits, nodes: search.nodes, dead_ends: search.dead_ends };
+        (decision, fits.then_some(search.placements))
+    }
+
+    // One column for each shape, covered once for every present of that shape, and one for
+    // each cell of the region. And a row for every position of every variant of every shape.
+    fn exact_cover(&self, region: &Region) -> (Decision, Option<Vec<Placement>>) {
+        let shapes = region.shape_quantity.len();
+        let cells = region.width * region.height;
+
+        // When the presents fill up the whole region, every single cell has to be covered,
+        // which narrows down the search a lot. Otherwise cells may stay empty.
+        let mut bounds = region.shape_quantity.clone();
+        let secondary = if self.total_present_size(region) == cells {
+            bounds.resize(shapes + cells, 1);
+            0
+        } else {
+            cells
+        };
+        let mut cover = ExactCover::new(&bounds, secondary);
+
+        // the placement for each row, to know what the rows of the solution mean
+        let mut rows = Vec::new();
+        let mut columns = Vec::new();
+        for (i, variants) in self.shape_variants.iter().enumerate() {
+            if region.shape_quantity[i] == 0 {
+                continue;
+            }
+            for (variant, shape) in variants.iter().enumerate() {
+                if shape.width > region.width || shape.height() > region.height {
+                    continue;
+                }
+                for y in 0..=region.height - shape.height() {
+                    for x in 0..=region.width - shape.width {
+                        columns.clear();
+                        columns.push(i);
+                        columns.extend(shape.cells().map(|(dx, dy)| shapes + (y + dy) * region.width + x + dx));
+                        cover.add_row(&columns);
+                        rows.push(Placement { shape: i, variant, x, y });
+                    }
+                }
+            }
+        }
+
+        let limit = self.budget.start();
+        let solution = cover.solve(|nodes| limit.reached(nodes));
+        if cover.gave_up() {
+            return (Decision::Undecided { nodes: cover.nodes() }, None);
+        }
+        let decision = Decision::Search { fits: solution.is_some(), nodes: cover.nodes(), dead_ends: 0 };
+        (decision, solution.map(|solution| solution.into_iter().map(|row| rows[row]).collect()))
+    }
+
+    // A line for each region with what decided it, and with --render a drawing of the ones that fit
+    fn report_regions(&self, decisions: &[Decision], placements: &[Option<Vec<Placement>>]) -> String {
+        let mut report = String::new();
+        for (i, region) in self.regions.iter().enumerate() {
+            if self.render {
+                report.push('\n');
+            }
+            report.push_str(&format!("Region {} ({}x{}): {}\n", i + 1, region.width, region.height, decisions[i]));
+            if let (true, Some(placements)) = (self.render, &placements[i]) {
+                report.push_str(&self.render(region, placements));
+            }
+        }
+        report
+    }
+
+    // Draw the region with a letter for each present, A to Z and then a to z (and then
+    // A again, so with that many presents neighbours could end up with the same letter)
+    fn render(&self, region: &Region, placements: &[Placement]) -> String {
+        const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+        let mut cells = vec![vec![b'.'; region.width]; region.height];
+        for (i, placement) in placements.iter().enumerate() {
+            let shape = &self.shape_variants[placement.shape][placement.variant];
+            for (x, y) in shape.cells() {
+                cells[placement.y + y][placement.x + x] = LETTERS[i % LETTERS.len()];
+            }
+        }
+
+        let mut rendered = String::new();
+        for row in cells {
+            rendered.push_str(&String::from_utf8_lossy(&row));
+            rendered.push('\n');
+        }
+        rendered
+    }
+
+    fn fit_the_presents(&self, search: &mut Search) -> bool {
+        let index = search.placements.len();
+        if index == search.presents.len() {
+            return true; // all shapes placed, wohoo \o/
+        }
+
+        let shape_index = search.presents[index];
+        let shape_variants = &self.shape_variants[shape_index];
+        let width = search.grid.width();
+
+        // Presents of the same shape are all the same, so there's no need to try them in every
+        // possible order: each one goes after the one before, or in another variant at the same spot
+        let (first, first_variant) = match index.checked_sub(1).map(|i| search.placements[i]) {
+            Some(previous) if previous.shape == shape_index => (previous.y * width + previous.x, previous.variant + 1),
+            _ => (0, 0),
+        };
+
+        // try to place this present in all positions and variations (well, one at a time,
+        // and only backtrack if needed)
+        // this is a bit too much because when we have presents placed already, we will always
+        // have occupied cells in the grid, so we could optimize a bit by skipping those positions
+        // but for now, let's keep it simple 
+        for position in first..width * search.grid.height() {
+            let (x, y) = (position % width, position / width);
+            let skip = if position == first { first_variant } else { 0 };
+            for (variant, shape) in shape_variants.iter().enumerate().skip(skip) {
+                if search.grid.can_fit(&shape.rows, shape.width, x, y) {
+                    if search.limit.reached(search.nodes) {
+                        search.gave_up = true;
+                        return false; // the search is over, no need to clean up
+                    }
+                    // if it fits, it sits...
+                    search.grid.place_shape(&shape.rows, x, y);
+                    search.nodes += 1;
+
+                    // unless it leaves gaps that make for too little room for the rest. Looking for
+                    // those takes a while, and it costs more than it saves while there's still room
+                    // to spare for another present, so only when it's getting tight.
+                    let area = width * search.grid.height();
+                    let used = search.remaining[0] - search.remaining[index + 1];
+                    let spare = area - used - search.dead - search.remaining[index + 1];
+                    let dead = if spare < search.smallest { search.dead_cells_around(shape, x, y) } else { 0 };
+                    if dead > spare {
+                        search.dead_ends += 1;
+                        search.grid.remove_shape(&shape.rows, x, y);
+                        continue;
+                    }
+
+                    search.dead += dead;
+                    search.placements.push(Placement { shape: shape_index, variant, x, y });
+                    // try to fit the next present
+                    if self.fit_the_presents(search) {
+                        return true;    // All good, we done!
+                    }
+                    if search.gave_up {
+                        return false;
+                    }
+                    // so we're back here because the shape didn't fit, we backtrack and try something else
+                    search.placements.pop();
+                    search.dead -= dead;
+                    search.grid.remove_shape(&shape.rows, x, y);
+                }
+            }
+        }
+
+        false // no valid placement found
+    }
+}
+
+// What the backtracking keeps track of while fitting the presents in one region
+struct Search {
+    grid: BitGrid,
+    presents: Vec<usize>,       // the shape of each present, in the order they're placed
+    remaining: Vec<usize>,      // the total size of the presents from each one on, 0 at the end
+    smallest: usize,            // the size of the smallest present
+    placements: Vec<Placement>, // where the ones placed so far went
+    dead: usize,                // empty cells in gaps too small for any present
+    limit: Limit,
+    nodes: u64,
+    dead_ends: u64,
+    gave_up: bool, // because of the limit
+}
+
+impl Search {
+    // The cells in the gaps that were closed off by the shape just placed here, which are
+    // too small for even the smallest present. Those can't be used anymore, and stay that way
+    // (any other gaps around the shape were part of the bigger area the shape went in).
+    fn dead_cells_around(&self, shape: &Shape, x: usize, y: usize) -> usize {
+        let mut dead = 0;
+        // the cells of the areas around the shape seen so far, big or small
+        let mut checked: Vec<(usize, usize)> = Vec::new();
+        for (dx, dy) in shape.cells() {
+            for neighbour in neighbours4(x + dx, y + dy, self.grid.width(), self.grid.height()) {
+                if self.grid.get(neighbour.0, neighbour.1) || checked.contains(&neighbour) {
+                    continue;
+                }
+                let area = self.grid.empty_area(neighbour.0, neighbour.1, self.smallest);
+                if area.len() < self.smallest {
+                    dead += area.len();
+                }
+                checked.extend(area);
+            }
+        }
+        dead
+    }
+}
+
+#[cfg(test)]
+mod tests;
\ No newline at end of file
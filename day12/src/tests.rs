@@ -1,6 +1,341 @@
-// use super::*;
+use super::*;
 
-// #[test]
-// fn dummy_test() {
-//     assert_eq!(1 + 1, 2);
-// }
\ No newline at end of file
+// The example from the puzzle description
+const EXAMPLE: &str = include_str!("../test.txt");
+
+// The example without its last region, which has no solution and takes a while to find out
+fn example_without_last_region() -> String {
+    let lines: Vec<&str> = EXAMPLE.lines().collect();
+    lines[..lines.len() - 1].join("\n")
+}
+
+#[test]
+fn example_part1_solvable_regions() {
+    let solver = Solver::parse(&example_without_last_region()).unwrap();
+    let packing = solver.part1();
+    assert_eq!((packing.count, packing.count_no_solution), (2, 0));
+}
+
+#[test]
+fn example_part1() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    let packing = solver.part1();
+    assert_eq!(packing.to_string(), "2");
+    assert_eq!((packing.regions, packing.count_oversized, packing.count_no_solution), (3, 0, 1));
+}
+
+#[test]
+fn example_part2() {
+    let solver = Solver::parse(EXAMPLE).unwrap();
+    assert_eq!(solver.part2(), "There is no part 2");
+}
+
+#[test]
+fn region_wider_than_64() {
+    // 22 presents side by side, the last ones well beyond the first 64 columns
+    let input = format!("{}\n66x3: 22 0 0 0 0 0\n", example_without_last_region());
+    let solver = Solver::parse(&input).unwrap();
+    let packing = solver.part1();
+    assert_eq!((packing.count, packing.regions), (3, 3));
+}
+
+#[test]
+fn shapes_are_normalized_by_translation() {
+    let shape = Shape::from_str("....\n..#.\n.##.\n");
+    assert_eq!(shape, Shape::from_str(".#\n##"));
+    assert_eq!((shape.width, shape.height(), shape.size), (2, 2, 3));
+    assert_eq!(shape.rows, vec![0b10, 0b11]);
+}
+
+#[test]
+fn rotate_and_flip_any_box() {
+    // ##### rotated is a column of 5, flipped it's the same
+    let line = Shape::from_str("#####");
+    assert_eq!(line.rotate(), Shape::from_str("#\n#\n#\n#\n#"));
+    assert_eq!(line.flip(), line);
+
+    // #.
+    // #.
+    // ##
+    let l = Shape::from_str("#.\n#.\n##");
+    assert_eq!(l.rotate(), Shape::from_str("###\n#.."));
+    assert_eq!(l.flip(), Shape::from_str(".#\n.#\n##"));
+    assert_eq!(l.rotate().rotate().rotate().rotate(), l);
+}
+
+#[test]
+fn variants_of_polyominoes() {
+    let variants = |s| Shape::from_str(s).generate_variants().len();
+    assert_eq!(variants("##\n##"), 1);
+    assert_eq!(variants("#####"), 2);
+    assert_eq!(variants(".##\n##."), 4);
+    assert_eq!(variants("#.\n#.\n##"), 8);
+    // The F pentomino, in a 3x3 box
+    assert_eq!(variants(".##\n##.\n.#."), 8);
+    // And a 4x4 cross, which is symmetric along the diagonal
+    assert_eq!(variants(".#..\n####\n.#..\n.#.."), 4);
+}
+
+#[test]
+fn catalog_of_other_shapes() {
+    // Two dominoes and an L tromino, that fill up a 3x3 region together
+    let input = "0:\n##.\n...\n...\n\n1:\n#..\n#..\n...\n\n2:\n.#.\n##.\n...\n\n\
+                 3:\n#..\n...\n...\n\n4:\n#..\n...\n...\n\n5:\n#..\n...\n...\n\n\
+                 3x3: 2 0 1 0 0 0\n3x3: 1 1 1 1 1 0\n2x2: 0 0 1 1 0 0\n2x2: 0 0 1 0 1 1\n";
+    let solver = Solver::parse(input).unwrap();
+    let packing = solver.part1();
+    assert_eq!((packing.count, packing.count_oversized, packing.count_no_solution), (3, 1, 0));
+}
+
+#[test]
+fn any_number_of_shapes_of_any_height() {
+    // A 4 high I tetromino and a 2x2 square, which fit a 4x3 region together,
+    // while two squares have room to spare in a 3x3 region but don't fit anyway
+    let input = "0:\n#\n#\n#\n#\n\n1:\n##\n##\n\n4x3: 1 1\n3x3: 0 2\n5x2: 0 2\n3x2: 1 1\n";
+    let solver = Solver::parse(input).unwrap();
+    assert_eq!(solver.shape_variants.len(), 2);
+    let packing = solver.part1();
+    assert_eq!((packing.count, packing.count_oversized, packing.count_no_solution), (2, 1, 1));
+}
+
+#[test]
+fn shape_counts_must_match_the_catalog() {
+    let error = Solver::parse("0:\n##\n\n1:\n#\n\n3x3: 1 1 1\n").err().unwrap();
+    assert_eq!(error.line, 7);
+    assert_eq!(error.column, 5);
+    assert!(error.message.contains("expected 2 shape counts"), "{}", error);
+
+    let error = Solver::parse("0:\n##\n\n3x3:\n").err().unwrap();
+    assert!(error.message.contains("expected 1 shape counts"), "{}", error);
+}
+
+#[test]
+fn shapes_are_numbered_in_order() {
+    let error = Solver::parse("0:\n##\n\n2:\n#\n\n3x3: 1 1\n").err().unwrap();
+    assert_eq!((error.line, error.column, error.text.as_str()), (4, 1, "2"));
+
+    let error = Solver::parse("0:\n##\n\n3x3: 1\n\n1:\n#\n").err().unwrap();
+    assert_eq!(error.line, 6);
+    assert!(error.message.contains("before the regions"), "{}", error);
+
+    let error = Solver::parse("0:\n\n1:\n#\n").err().unwrap();
+    assert_eq!(error.line, 1);
+    assert!(error.message.contains("no '#'"), "{}", error);
+}
+
+#[test]
+fn shapes_fit_in_64_both_ways() {
+    // 64 high is still fine, it's just 64 wide when it's rotated
+    let input = format!("0:\n{}\n4x64: 1\n", "#\n".repeat(64));
+    assert!(Solver::parse(&input).is_ok());
+
+    let input = format!("0:\n{}\n4x65: 1\n", "#\n".repeat(65));
+    let error = Solver::parse(&input).err().unwrap();
+    assert_eq!(error.line, 1);
+    assert!(error.message.contains("at most 64 high"), "{}", error);
+
+    let input = format!("0:\n{}\n\n65x4: 1\n", "#".repeat(65));
+    let error = Solver::parse(&input).err().unwrap();
+    assert_eq!(error.line, 2);
+    assert!(error.message.contains("at most 64 wide"), "{}", error);
+}
+
+fn options(args: &[(&str, Option<&str>)]) -> Options {
+    let mut options = Options::new();
+    for &(name, value) in args {
+        options.add(name, value);
+    }
+    options
+}
+
+// The solver for the input, configured with the options, all of which it should take
+fn configured(input: &str, args: &[(&str, Option<&str>)]) -> Solver {
+    let mut solver = Solver::parse(input).unwrap();
+    let mut options = options(args);
+    solver.configure(&mut options).unwrap();
+    assert!(options.is_empty());
+    solver
+}
+
+#[test]
+fn example_part1_dlx() {
+    let packing = configured(EXAMPLE, &[("backend", Some("dlx"))]).part1();
+    assert_eq!((packing.count, packing.count_oversized, packing.count_no_solution), (2, 0, 1));
+}
+
+#[test]
+fn dlx_agrees_with_backtracking() {
+    let input = "0:\n#\n#\n#\n#\n\n1:\n##\n##\n\n2:\n###\n#..\n\n\
+                 4x3: 1 1 0\n3x3: 0 2 0\n5x2: 0 2 0\n3x2: 1 1 0\n4x4: 0 1 2\n3x4: 0 0 3\n5x5: 1 2 1\n";
+    let packings = ["backtracking", "dlx"].map(|backend| {
+        let packing = configured(input, &[("backend", Some(backend))]).part1();
+        (packing.count, packing.count_oversized, packing.count_no_solution)
+    });
+    assert_eq!(packings[0], packings[1]);
+}
+
+#[test]
+fn tight_region_with_dlx() {
+    // L trominoes (and dominoes) filling up regions exactly, so every cell must be covered,
+    // which works out for 4x4 and 2x3 but not for 3x3
+    let input = "0:\n#.\n##\n\n1:\n##\n\n4x4: 4 2\n2x3: 2 0\n3x3: 3 0\n";
+    let packing = configured(input, &[("backend", Some("dlx"))]).part1();
+    assert_eq!((packing.count, packing.count_oversized, packing.count_no_solution), (2, 0, 1));
+}
+
+#[test]
+fn unknown_backend() {
+    let mut solver = Solver::parse(EXAMPLE).unwrap();
+    let error = solver.configure(&mut options(&[("backend", Some("magic"))])).unwrap_err();
+    assert!(error.contains("expected backtracking or dlx"), "{}", error);
+}
+
+// The placements should have all of the presents of the region, inside of it, without any overlap
+fn check_placements(solver: &Solver, region: &Region, placements: &[Placement]) {
+    let mut counts = vec![0; region.shape_quantity.len()];
+    let mut cells = vec![false; region.width * region.height];
+    for placement in placements {
+        counts[placement.shape] += 1;
+        let shape = &solver.shape_variants[placement.shape][placement.variant];
+        for (x, y) in shape.cells() {
+            let (x, y) = (placement.x + x, placement.y + y);
+            assert!(x < region.width && y < region.height, "{:?} is outside of the region", placement);
+            assert!(!cells[y * region.width + x], "{:?} overlaps another present", placement);
+            cells[y * region.width + x] = true;
+        }
+    }
+    assert_eq!(counts, region.shape_quantity);
+}
+
+#[test]
+fn placements_of_both_backends() {
+    for backend in ["backtracking", "dlx"] {
+        let solver = configured(&example_without_last_region(), &[("backend", Some(backend))]);
+        let packing = solver.part1();
+        for (region, placements) in solver.regions.iter().zip(packing.placements()) {
+            check_placements(&solver, region, placements.as_ref().unwrap());
+        }
+    }
+}
+
+#[test]
+fn render_the_regions() {
+    let input = "0:\n#.\n##\n\n1:\n##\n\n2x3: 2 0\n3x3: 0 5\n3x2: 1 1\n";
+    let packing = configured(input, &[("render", None)]).part1();
+    assert_eq!(packing.placements()[0].as_ref().map(|placements| placements.len()), Some(2));
+    assert_eq!(packing.placements()[1], None);
+
+    let rendered = format!("{:#}", packing);
+    let (stats, regions) = rendered.split_once('\n').unwrap();
+    assert_eq!(stats, "2 (of 3 regions, 1 oversized, 0 in slots, 0 with no solution)");
+    assert!(regions.contains("Region 1 (2x3): fits after placing "), "{}", regions);
+    assert!(regions.contains("Region 2 (3x3): oversized, "), "{}", regions);
+    // Two presents in the last region, where the drawing is the last thing
+    let drawing: Vec<&str> = regions.lines().rev().take(2).collect();
+    assert_eq!(drawing.iter().map(|row| row.len()).collect::<Vec<_>>(), vec![3, 3]);
+    assert_eq!(drawing.concat().matches('A').count(), 3);
+    assert_eq!(drawing.concat().matches('B').count(), 2);
+
+    // and without --render, just the stats
+    let packing = Solver::parse(input).unwrap().part1();
+    assert_eq!(format!("{:#}", packing), "2 (of 3 regions, 1 oversized, 0 in slots, 0 with no solution)");
+}
+
+#[test]
+fn slots_for_roomy_regions() {
+    // 4 presents in their own 3x3 slots, but not 5 of them (even if they'd fit together)
+    let input = format!("{}\n6x6: 1 1 0 2 0 0\n6x7: 2 0 1 0 1 1\n", example_without_last_region());
+    let solver = Solver::parse(&input).unwrap();
+    let packing = solver.part1();
+    assert_eq!(packing.decisions()[2], Decision::Slots);
+    assert!(matches!(packing.decisions()[3], Decision::Search { fits: true, .. }));
+    for (region, placements) in solver.regions.iter().zip(packing.placements()) {
+        check_placements(&solver, region, placements.as_ref().unwrap());
+    }
+    assert_eq!((packing.count, packing.count_slots), (4, 1));
+}
+
+#[test]
+fn dead_ends_in_tight_regions() {
+    // L trominoes can't fill up a 3x3 region, and that's found out by the gaps they leave
+    let solver = Solver::parse("0:\n#.\n##\n\n3x3: 3\n").unwrap();
+    match solver.part1().decisions()[0] {
+        Decision::Search { fits, dead_ends, .. } => assert!(!fits && dead_ends > 0),
+        decision => panic!("unexpected {:?}", decision),
+    }
+}
+
+#[test]
+fn report_how_the_regions_were_decided() {
+    let input = "0:\n#.\n##\n\n3x3: 3\n3x3: 4\n6x6: 4\n";
+    let report = format!("{:#}", configured(input, &[("report", None)]).part1());
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], "1 (of 3 regions, 1 oversized, 1 in slots, 1 with no solution)");
+    assert!(lines[1].starts_with("Region 1 (3x3): no solution after placing "), "{}", lines[1]);
+    assert!(lines[1].ends_with(" dead ends with too small gaps)"), "{}", lines[1]);
+    assert_eq!(lines[2], "Region 2 (3x3): oversized, the presents take up more room than there is");
+    assert_eq!(lines[3], "Region 3 (6x6): fits, with a slot for each present");
+}
+
+#[test]
+fn flags_stay_on() {
+    // Configuring again, without the flags, only changes what's given
+    let mut solver = configured(EXAMPLE, &[("report", None), ("render", None)]);
+    solver.configure(&mut options(&[("backend", Some("dlx"))])).unwrap();
+    assert!(solver.report && solver.render);
+}
+
+#[test]
+fn both_backends_decide_the_same() {
+    let input = "0:\n#.\n##\n\n1:\n##\n\n4x4: 4 2\n2x3: 2 0\n3x3: 3 0\n5x5: 2 1\n5x5: 6 1\n";
+    let fits = ["backtracking", "dlx"].map(|backend| {
+        let packing = configured(input, &[("backend", Some(backend))]).part1();
+        packing.decisions().iter().map(Decision::fits).collect::<Vec<_>>()
+    });
+    assert_eq!(fits[0], fits[1]);
+}
+
+#[test]
+fn same_results_with_any_number_of_threads() {
+    let input = format!(
+        "{}\n6x6: 1 1 0 2 0 0\n4x4: 0 0 0 0 3 0\n66x3: 22 0 0 0 0 0\n5x5: 1 1 1 1 1 1\n",
+        example_without_last_region()
+    );
+    let packings: Vec<Packing> = ["1", "2", "16"].iter().map(|jobs| configured(&input, &[("jobs", Some(jobs))]).part1()).collect();
+    for packing in packings.iter() {
+        assert_eq!(format!("{:#}", packing), "4 (of 6 regions, 2 oversized, 2 in slots, 0 with no solution)");
+        assert_eq!(packing.decisions(), packings[0].decisions());
+        assert_eq!(packing.placements(), packings[0].placements());
+    }
+}
+
+#[test]
+fn at_least_one_thread() {
+    let mut solver = Solver::parse(EXAMPLE).unwrap();
+    assert!(solver.configure(&mut options(&[("jobs", Some("0"))])).is_err());
+}
+
+#[test]
+fn undecided_regions() {
+    let packing = configured(EXAMPLE, &[("node-limit", Some("1000"))]).part1();
+    assert_eq!(format!("{:#}", packing), "1 (of 3 regions, 0 oversized, 0 in slots, 0 with no solution, 2 undecided)");
+    assert_eq!(packing.decisions()[0], Decision::Search { fits: true, nodes: 3, dead_ends: 0 });
+    assert_eq!(packing.decisions()[1], Decision::Undecided { nodes: 1000 });
+    assert_eq!(packing.decisions()[1].to_string(), "undecided, gave up after placing 1000 presents");
+    assert_eq!(packing.placements()[1], None);
+}
+
+#[test]
+fn undecided_regions_with_dlx() {
+    let packing = configured(EXAMPLE, &[("backend", Some("dlx")), ("node-limit", Some("5"))]).part1();
+    assert_eq!((packing.count, packing.count_undecided), (1, 2));
+}
+
+#[test]
+fn undecided_after_a_timeout() {
+    // No time at all is over before the first present is placed, in every region that needs searching
+    let packing = configured(EXAMPLE, &[("timeout", Some("0"))]).part1();
+    assert_eq!(packing.decisions(), [Decision::Undecided { nodes: 0 }, Decision::Undecided { nodes: 0 }, Decision::Undecided { nodes: 0 }]);
+    assert_eq!((packing.count, packing.count_no_solution, packing.count_undecided), (0, 0, 3));
+}
@@ -6,20 +6,6 @@ if [ -z "$1" ]; then
     exit 1
 fi
 
-NEW_DAY=$1
-
-if [ -d "$NEW_DAY" ]; then
-    echo "Error: Directory '$NEW_DAY' already exists."
-    exit 1
-fi
-
-# Just simply copy the template template_day directory
-cp -r template_day "$NEW_DAY"
-
-# Update the package name in Cargo.toml
-sed "s/name = \"template_day\"/name = \"$NEW_DAY\"/" "$NEW_DAY/Cargo.toml" > "$NEW_DAY/Cargo.toml.tmp" && mv "$NEW_DAY/Cargo.toml.tmp" "$NEW_DAY/Cargo.toml"
-
-# Makes no sense to keep the 'target' directory...
-rm -rf "$NEW_DAY/target"
-
-echo "Successfully created '$NEW_DAY' based on 'template_day'."
+# The runner does all the work now: copying template_day, creating the input, test
+# and answers files, and registering the day in the workspace and in the runner itself
+cargo run -q -p aoc -- new "$1"
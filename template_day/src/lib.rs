@@ -0,0 +1,44 @@
+use aoc_common::{Day, ParseError, lines};
+
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    // For just splitting into a vector of strings, this function could just be:
+    //    Ok(input.lines().map(String::from).collect())
+    // but most day'es will be parsing data here, line by line, so let's have
+    // the loop here...
+    let mut data: Vec<String> = Vec::new();
+
+    // Parse each line of the input accordingly
+    // (use line.parse(part, "what") and line.error(part, "message") to point out bad input,
+    // and have a look in aoc_common for grid, graph and interval helpers for the solving)
+    for line in lines(Solver::DAY, input) {
+        data.push(line.text.to_string());
+    }
+
+    Ok(data)
+}
+
+pub struct Solver {
+    data: Vec<String>,
+}
+
+impl Day for Solver {
+    const DAY: u8 = 0; // set to the day number by aoc new
+
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Solver { data: parse_input(input)? })
+    }
+
+    fn part1(&self) -> u64 {
+        self.data.len() as u64
+    }
+
+    fn part2(&self) -> u64 {
+        self.data.len() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests;
\ No newline at end of file
@@ -1,73 +0,0 @@
-use std::env;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::time::Instant;
-
-fn main() {
-    // Get the input filename from command line arguments or default to "input.txt"
-    let args: Vec<String> = env::args().collect();
-    let filename = args.get(1).map(|s| s.as_str()).unwrap_or("input.txt");
-
-    // Read the input file
-    match read_input(filename) {
-        // If successful, run parts 1 and 2 and measure their execution time
-        Ok(contents) => {
-            let solver = Solver::new(&contents);
-
-            // Part 1
-            let start = Instant::now();
-            let result1 = solver.part1();
-            println!("Part 1: {}\n        {:?}", result1, start.elapsed());
-
-            // Part 2
-            let start = Instant::now();
-            let result2 = solver.part2();
-            println!("\nPart 2: {}\n        {:?}", result2, start.elapsed());
-        }
-
-        // If there was an error reading the file, print an error message
-        Err(e) => {
-            eprintln!("Error reading input {}: {}", filename, e);
-        }
-    }
-}
-
-fn read_input(file: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
-    // For just reading into a vector of strings, this function could just be:
-    //    let content = fs::read_to_string(file)?;
-    //    Ok(content.lines().map(String::from).collect())
-    // but most day'es well be parsing data here, line by line, so let's have
-    // the buffered reading here...
-    let input_file = File::open(file)?;
-    let buffered = BufReader::new(input_file);
-
-    let mut data: Vec<String> = Vec::new();
-
-    // Read each line from the file and parse it accordingly
-    for line in buffered.lines() {
-        data.push(line?);
-    }
-
-    Ok(data)
-}
-
-struct Solver<'a> {
-    data: &'a [String],
-}
-
-impl<'a> Solver<'a> {
-    fn new(data: &'a [String]) -> Self {
-        Solver { data }
-    }
-
-    fn part1(&self) -> u64 {
-        self.data.iter().count() as u64
-    }
-
-    fn part2(&self) -> u64 {
-        self.data.iter().count() as u64
-    }
-}
-
-#[cfg(test)]
-mod tests;
\ No newline at end of file